@@ -1,4 +1,4 @@
-use crate::{prelude::*, Vec};
+use crate::{convert::ToConstraintFieldGadget, fields::fp::FpVar, prelude::*, Vec};
 use ark_ff::{Field, PrimeField};
 use ark_relations::r1cs::SynthesisError;
 
@@ -219,3 +219,155 @@ impl<T: EqGadget<F> + R1CSVar<F>, F: PrimeField, const N: usize> EqGadget<F> for
             .conditional_enforce_not_equal(other.as_slice(), should_enforce)
     }
 }
+
+/// Accumulates equality checks between variables of different types, and
+/// enforces all of them at once via a random linear combination.
+///
+/// Every enqueued check is first reduced to one or more differences
+/// `a_i - b_i` of field elements. Upon [`BatchVerifier::finalize`], these are
+/// combined into the single check `sum_i challenge^i * (a_i - b_i) == 0`.
+/// Since every difference occupies its own power of `challenge`, differences
+/// arising from checks of different types cannot cancel each other out.
+///
+/// # Soundness
+///
+/// This is only sound if `challenge` is sampled uniformly at random *after*
+/// all enqueued variables have been fixed (e.g., via Fiat-Shamir). In that
+/// case, the soundness error is at most `n / |F|`, where `n` is the number of
+/// enqueued differences.
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct BatchVerifier<F: PrimeField> {
+    differences: Vec<FpVar<F>>,
+}
+
+impl<F: PrimeField> BatchVerifier<F> {
+    /// Constructs an empty `BatchVerifier`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enqueues the check `a == b`.
+    ///
+    /// This *does not* create any new variables or constraints.
+    pub fn enqueue_eq(&mut self, a: &FpVar<F>, b: &FpVar<F>) {
+        self.differences.push(a - b);
+    }
+
+    /// Enqueues the check `a == b`, where `a` and `b` are packed into field
+    /// elements in chunks of `F::MODULUS_BIT_SIZE - 1` bits.
+    ///
+    /// This *does not* create any new variables or constraints.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `a.len() != b.len()`.
+    pub fn enqueue_bits_eq(
+        &mut self,
+        a: &[Boolean<F>],
+        b: &[Boolean<F>],
+    ) -> Result<(), SynthesisError> {
+        assert_eq!(a.len(), b.len());
+        let chunk_size = F::MODULUS_BIT_SIZE as usize - 1;
+        for (a, b) in a.chunks(chunk_size).zip(b.chunks(chunk_size)) {
+            self.differences
+                .push(Boolean::le_bits_to_fp(a)? - Boolean::le_bits_to_fp(b)?);
+        }
+        Ok(())
+    }
+
+    /// Enqueues the check `a == b`, where `a` and `b` are packed into field
+    /// elements via [`ToConstraintFieldGadget`].
+    ///
+    /// This *does not* create any new variables or constraints.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `a.len() != b.len()`.
+    pub fn enqueue_bytes_eq(
+        &mut self,
+        a: &[UInt8<F>],
+        b: &[UInt8<F>],
+    ) -> Result<(), SynthesisError> {
+        assert_eq!(a.len(), b.len());
+        let a = a.to_constraint_field()?;
+        let b = b.to_constraint_field()?;
+        self.differences
+            .extend(a.iter().zip(&b).map(|(a, b)| a - b));
+        Ok(())
+    }
+
+    /// Enforces all enqueued checks, using `challenge` as the randomness for
+    /// the linear combination.
+    ///
+    /// This requires one constraint per enqueued difference, or *one*
+    /// constraint in total if `challenge` is a constant.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn finalize(self, challenge: &FpVar<F>) -> Result<(), SynthesisError> {
+        // Evaluate the polynomial with coefficients `self.differences` at
+        // `challenge` via Horner's method.
+        let combination = self
+            .differences
+            .iter()
+            .rev()
+            .fold(FpVar::zero(), |acc, d| acc * challenge + d);
+        combination.enforce_equal(&FpVar::zero())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::BatchVerifier;
+    use crate::{alloc::AllocVar, boolean::Boolean, fields::fp::FpVar, uint8::UInt8};
+    use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+    use ark_std::UniformRand;
+    use ark_test_curves::bls12_381::Fr;
+
+    fn check(mismatch: Option<usize>) -> Result<bool, SynthesisError> {
+        let mut rng = ark_std::test_rng();
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let perturb = |i: usize| mismatch == Some(i);
+
+        let mut verifier = BatchVerifier::new();
+
+        let x = Fr::rand(&mut rng);
+        let a = FpVar::new_witness(cs.clone(), || Ok(x))?;
+        let b = FpVar::new_witness(cs.clone(), || {
+            Ok(if perturb(0) { x + Fr::from(1u64) } else { x })
+        })?;
+        verifier.enqueue_eq(&a, &b);
+
+        let bits = (0..300).map(|i| i % 3 == 0).collect::<Vec<_>>();
+        let mut other_bits = bits.clone();
+        other_bits[299] ^= perturb(1);
+        let a = Vec::<Boolean<Fr>>::new_witness(cs.clone(), || Ok(bits))?;
+        let b = Vec::<Boolean<Fr>>::new_witness(cs.clone(), || Ok(other_bits))?;
+        verifier.enqueue_bits_eq(&a, &b)?;
+
+        let bytes = b"batched equality".to_vec();
+        let mut other_bytes = bytes.clone();
+        if perturb(2) {
+            other_bytes[3] = b'c';
+        }
+        let a = UInt8::new_witness_vec(cs.clone(), &bytes)?;
+        let b = UInt8::new_witness_vec(cs.clone(), &other_bytes)?;
+        verifier.enqueue_bytes_eq(&a, &b)?;
+
+        let challenge = FpVar::new_input(cs.clone(), || Ok(Fr::rand(&mut rng)))?;
+        let num_constraints = cs.num_constraints();
+        verifier.finalize(&challenge)?;
+        // One difference for the field elements, two for the 300 bits, and one
+        // for the 16 bytes.
+        assert_eq!(cs.num_constraints() - num_constraints, 4);
+        Ok(cs.is_satisfied().unwrap())
+    }
+
+    #[test]
+    fn batch_verifier() -> Result<(), SynthesisError> {
+        assert!(check(None)?);
+        for i in 0..3 {
+            assert!(!check(Some(i))?);
+        }
+        Ok(())
+    }
+}
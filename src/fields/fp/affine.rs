@@ -0,0 +1,75 @@
+use ark_ff::PrimeField;
+use ark_relations::r1cs::SynthesisError;
+
+use crate::{fields::fp::FpVar, prelude::*};
+
+impl<F: PrimeField> FpVar<F> {
+    /// Enforces that `y == slope * x + intercept`.
+    ///
+    /// This emits the single constraint `slope * x == y - intercept`, and
+    /// does not allocate any new variables. If `slope` or `x` is a constant,
+    /// the multiplication is free, and the constraint is linear.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn enforce_affine_relation(
+        y: &Self,
+        slope: &Self,
+        x: &Self,
+        intercept: &Self,
+    ) -> Result<(), SynthesisError> {
+        slope.mul_equals(x, &(y - intercept))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{alloc::AllocVar, fields::fp::FpVar};
+    use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+    use ark_std::UniformRand;
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn enforce_affine_relation() -> Result<(), SynthesisError> {
+        let mut rng = ark_std::test_rng();
+        let slope = Fr::rand(&mut rng);
+        let x = Fr::rand(&mut rng);
+        let intercept = Fr::rand(&mut rng);
+        let y = slope * x + intercept;
+
+        for (slope_is_constant, x_is_constant) in [(false, false), (true, false), (false, true)] {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let new_fp = |v: Fr, is_constant: bool| {
+                if is_constant {
+                    FpVar::new_constant(cs.clone(), v)
+                } else {
+                    FpVar::new_witness(cs.clone(), || Ok(v))
+                }
+            };
+            let y_var = new_fp(y, false)?;
+            let slope_var = new_fp(slope, slope_is_constant)?;
+            let x_var = new_fp(x, x_is_constant)?;
+            let intercept_var = new_fp(intercept, false)?;
+            let num_witnesses = cs.num_witness_variables();
+
+            FpVar::enforce_affine_relation(&y_var, &slope_var, &x_var, &intercept_var)?;
+            assert_eq!(cs.num_constraints(), 1);
+            assert_eq!(cs.num_witness_variables(), num_witnesses);
+            assert!(cs.is_satisfied().unwrap());
+
+            // Computing the product explicitly allocates a new witness unless
+            // the multiplication is folded into a linear combination, which
+            // happens exactly when `slope` or `x` is a constant.
+            let num_witnesses = cs.num_witness_variables();
+            let _ = &slope_var * &x_var;
+            let product_is_folded = slope_is_constant || x_is_constant;
+            assert_eq!(
+                cs.num_witness_variables() == num_witnesses,
+                product_is_folded
+            );
+
+            let wrong_y_var = new_fp(y + Fr::from(1u64), false)?;
+            FpVar::enforce_affine_relation(&wrong_y_var, &slope_var, &x_var, &intercept_var)?;
+            assert!(!cs.is_satisfied().unwrap());
+        }
+        Ok(())
+    }
+}
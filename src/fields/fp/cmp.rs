@@ -1,8 +1,10 @@
 use crate::{
     boolean::Boolean,
+    cmp::CmpGadget,
     convert::ToBitsGadget,
     fields::{fp::FpVar, FieldVar},
     prelude::*,
+    uint::{PrimUInt, UInt},
 };
 use ark_ff::PrimeField;
 use ark_relations::r1cs::{SynthesisError, Variable};
@@ -77,6 +79,36 @@ impl<F: PrimeField> FpVar<F> {
         left.is_smaller_than_unchecked(&right)
     }
 
+    /// Enforces that `self < 2^exponent`, where `exponent` is a variable.
+    ///
+    /// This decomposes `self` into `max_exp` bits, and then enforces that
+    /// every bit at a position `i >= exponent` is zero. Hence, the constraint
+    /// system is unsatisfiable if `self >= 2^min(exponent, max_exp)`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `max_exp >= F::MODULUS_BIT_SIZE`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn enforce_less_than_pow2_var<const N: usize, T: PrimUInt>(
+        &self,
+        exponent: &UInt<N, T, F>,
+        max_exp: usize,
+    ) -> Result<(), SynthesisError>
+    where
+        F: From<T>,
+    {
+        let (bits, _) = self.to_bits_le_with_top_bits_zero(max_exp)?;
+        for (i, bit) in bits.iter().enumerate() {
+            // If `i` does not fit in `T`, then `i` is larger than `exponent`.
+            let is_at_or_above_exponent = match T::from(i) {
+                Some(i) => UInt::constant(i).is_ge(exponent)?,
+                None => Boolean::TRUE,
+            };
+            bit.conditional_enforce_equal(&Boolean::FALSE, &is_at_or_above_exponent)?;
+        }
+        Ok(())
+    }
+
     fn process_cmp_inputs(
         &self,
         other: &Self,
@@ -155,7 +187,7 @@ impl<F: PrimeField> FpVar<F> {
 mod test {
     use ark_std::{cmp::Ordering, rand::Rng};
 
-    use crate::{alloc::AllocVar, fields::fp::FpVar};
+    use crate::{alloc::AllocVar, fields::fp::FpVar, uint8::UInt8};
     use ark_ff::{PrimeField, UniformRand};
     use ark_relations::r1cs::ConstraintSystem;
     use ark_test_curves::bls12_381::Fr;
@@ -239,4 +271,32 @@ mod test {
             assert!(cs.is_satisfied().unwrap());
         }
     }
+
+    #[test]
+    fn test_less_than_pow2_var() {
+        let cases = [
+            (0u64, 0u8, true),
+            (1, 0, false),
+            (1, 1, true),
+            (7, 3, true),
+            (8, 3, false),
+            (5, 3, true),
+            (255, 8, true),
+            (256, 8, false),
+            (65535, 16, true),
+            (65536, 16, false),
+            // The bound is capped at `2^max_exp`.
+            (1000, 20, true),
+            (65536, 20, false),
+        ];
+        for (value, exponent, expected) in cases {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let value_var = FpVar::new_witness(cs.clone(), || Ok(Fr::from(value))).unwrap();
+            let exponent_var = UInt8::new_witness(cs.clone(), || Ok(exponent)).unwrap();
+            value_var
+                .enforce_less_than_pow2_var(&exponent_var, 16)
+                .unwrap();
+            assert_eq!(cs.is_satisfied().unwrap(), expected);
+        }
+    }
 }
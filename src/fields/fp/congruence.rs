@@ -0,0 +1,104 @@
+use ark_ff::PrimeField;
+use ark_relations::r1cs::SynthesisError;
+use num_bigint::BigUint;
+
+use crate::{fields::fp::FpVar, prelude::*};
+
+impl<F: PrimeField> FpVar<F> {
+    /// Enforces that `a ≡ b (mod m)`, where `a` and `b` are interpreted as
+    /// integers.
+    ///
+    /// This witnesses the signed integer `k = (a - b) / m`, enforces that
+    /// `k` lies in `[-2^(w - 1), 2^(w - 1))` for
+    /// `w = F::MODULUS_BIT_SIZE - 1 - bits(m)`, and then enforces that
+    /// `k * m == a - b`. In total, this requires `w + 2` constraints.
+    ///
+    /// If both `a` and `b` are constants, the congruence is instead checked
+    /// natively, and this returns `SynthesisError::Unsatisfiable` if it does
+    /// not hold.
+    ///
+    /// # Soundness
+    ///
+    /// This gadget is only sound and complete if `a` and `b` are both smaller
+    /// than `2^(F::MODULUS_BIT_SIZE - 3)`. This guarantees that neither
+    /// `k * m` nor `a - b` wraps around the modulus of `F`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `m == 0`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn enforce_congruent(a: &Self, b: &Self, m: u64) -> Result<(), SynthesisError> {
+        assert_ne!(m, 0);
+        let m_bits = (u64::BITS - m.leading_zeros()) as usize;
+        let k_bits = F::MODULUS_BIT_SIZE as usize - 1 - m_bits;
+
+        if let (Self::Constant(a), Self::Constant(b)) = (a, b) {
+            let m = BigUint::from(m);
+            let (a, b): (BigUint, BigUint) = ((*a).into(), (*b).into());
+            return if a % &m == b % &m {
+                Ok(())
+            } else {
+                Err(SynthesisError::Unsatisfiable)
+            };
+        }
+
+        let k = Self::new_witness(a.cs().or(b.cs()), || {
+            let a: BigUint = a.value()?.into();
+            let b: BigUint = b.value()?.into();
+            let m = BigUint::from(m);
+            if a >= b {
+                Ok(F::from((a - b) / m))
+            } else {
+                Ok(-F::from((b - a) / m))
+            }
+        })?;
+
+        let _ = k.enforce_signed_range(k_bits)?;
+        (k * F::from(m)).enforce_equal(&(a - b))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{alloc::AllocVar, fields::fp::FpVar};
+    use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+    use ark_test_curves::bls12_381::Fr;
+
+    const CASES: [(u64, u64, u64); 12] = [
+        (23, 11, 12),
+        (11, 23, 12),
+        (59, 119, 60),
+        (5, 5, 7),
+        (0, 1000, 1000),
+        (1 << 40, 0, 2),
+        (1, 0, 1),
+        (23, 10, 12),
+        (10, 23, 12),
+        (0, 1, 7),
+        (1, 0, 7),
+        (1 << 40, 1, 1000),
+    ];
+
+    #[test]
+    fn enforce_congruent() -> Result<(), SynthesisError> {
+        for (a, b, m) in CASES {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let a_var = FpVar::new_witness(cs.clone(), || Ok(Fr::from(a)))?;
+            let b_var = FpVar::new_witness(cs.clone(), || Ok(Fr::from(b)))?;
+            FpVar::enforce_congruent(&a_var, &b_var, m)?;
+            assert_eq!(cs.is_satisfied().unwrap(), a % m == b % m);
+
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let a_var = FpVar::new_witness(cs.clone(), || Ok(Fr::from(a)))?;
+            let b_const = FpVar::new_constant(cs.clone(), Fr::from(b))?;
+            FpVar::enforce_congruent(&a_var, &b_const, m)?;
+            assert_eq!(cs.is_satisfied().unwrap(), a % m == b % m);
+
+            let a_const = FpVar::Constant(Fr::from(a));
+            let b_const = FpVar::Constant(Fr::from(b));
+            let result = FpVar::enforce_congruent(&a_const, &b_const, m);
+            assert_eq!(result.is_ok(), a % m == b % m);
+        }
+        Ok(())
+    }
+}
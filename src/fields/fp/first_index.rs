@@ -0,0 +1,74 @@
+use ark_ff::PrimeField;
+use ark_relations::r1cs::SynthesisError;
+
+use crate::{boolean::Boolean, fields::fp::FpVar, prelude::*};
+
+impl<F: PrimeField> FpVar<F> {
+    /// Enforces that `index` is the position of the first occurrence of
+    /// `target` in `values`, where `index_bits` is the *little-endian*
+    /// representation of `index`.
+    ///
+    /// That is, this enforces that `index < values.len()`, that
+    /// `values[index] == target`, and that `values[i] != target` for all
+    /// `i < index`. In particular, the constraint system is unsatisfiable if
+    /// `target` does not occur in `values`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `index_bits.len() >= F::MODULUS_BIT_SIZE`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn enforce_first_index_of(
+        values: &[Self],
+        target: &Self,
+        index_bits: &[Boolean<F>],
+    ) -> Result<(), SynthesisError> {
+        assert!(index_bits.len() < F::MODULUS_BIT_SIZE as usize);
+        let index = Boolean::le_bits_to_fp(index_bits)?;
+        // After processing position `i`, `reached` is set if and only if
+        // `index <= i`.
+        let mut reached = Boolean::FALSE;
+        for (i, value) in values.iter().enumerate() {
+            let is_index = index.is_eq(&Self::constant(F::from(i as u64)))?;
+            reached |= &is_index;
+            value.conditional_enforce_equal(target, &is_index)?;
+            value.conditional_enforce_not_equal(target, &!&reached)?;
+        }
+        reached.enforce_equal(&Boolean::TRUE)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{alloc::AllocVar, boolean::Boolean, fields::fp::FpVar};
+    use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+    use ark_test_curves::bls12_381::Fr;
+
+    fn check(target: u64, index: u8) -> Result<bool, SynthesisError> {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let values = [5u64, 3, 8, 3, 9].map(Fr::from);
+        let values = Vec::<FpVar<Fr>>::new_witness(cs.clone(), || Ok(values))?;
+        let target = FpVar::new_input(cs.clone(), || Ok(Fr::from(target)))?;
+        let index_bits = (0..3).map(|i| (index >> i) & 1 == 1).collect::<Vec<_>>();
+        let index_bits = Vec::<Boolean<Fr>>::new_witness(cs.clone(), || Ok(index_bits))?;
+        FpVar::enforce_first_index_of(&values, &target, &index_bits)?;
+        Ok(cs.is_satisfied().unwrap())
+    }
+
+    #[test]
+    fn enforce_first_index_of() -> Result<(), SynthesisError> {
+        assert!(check(5, 0)?);
+        assert!(check(3, 1)?);
+        assert!(check(8, 2)?);
+        assert!(check(9, 4)?);
+
+        // A later occurrence is rejected.
+        assert!(!check(3, 3)?);
+        // An index pointing to a different value is rejected.
+        assert!(!check(3, 2)?);
+        // An absent target is rejected for every index.
+        for index in 0..8 {
+            assert!(!check(7, index)?);
+        }
+        Ok(())
+    }
+}
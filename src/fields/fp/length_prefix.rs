@@ -0,0 +1,121 @@
+use ark_ff::PrimeField;
+use ark_relations::r1cs::SynthesisError;
+use ark_std::vec::Vec;
+
+use crate::{boolean::Boolean, fields::fp::FpVar, prelude::*};
+
+impl<F: PrimeField> FpVar<F> {
+    /// Encodes `values` as a length-prefixed vector of `max_len + 1` elements:
+    /// the first element is `values.len()`, and the remaining elements are
+    /// `values`, padded with zeros to `max_len` elements.
+    ///
+    /// This *does not* create any new variables or constraints.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `values.len() > max_len`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn encode_with_length(
+        values: &[Self],
+        max_len: usize,
+    ) -> Result<Vec<Self>, SynthesisError> {
+        assert!(values.len() <= max_len);
+        let mut encoded = Vec::with_capacity(max_len + 1);
+        encoded.push(Self::constant(F::from(values.len() as u64)));
+        encoded.extend_from_slice(values);
+        encoded.resize(max_len + 1, Self::zero());
+        Ok(encoded)
+    }
+
+    /// Decodes a length-prefixed vector produced by
+    /// [`FpVar::encode_with_length`], and outputs the length along with the
+    /// `max_len = encoded.len() - 1` payload elements.
+    ///
+    /// Since the length may be a variable, the payload is output in its
+    /// padded form. This enforces that the length is at most `max_len`, and
+    /// that every payload element at a position greater than or equal to the
+    /// length is zero. Thus, the active prefix of the payload consists of
+    /// exactly the first `length` elements.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `encoded` is empty.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn decode_with_length(encoded: &[Self]) -> Result<(Self, Vec<Self>), SynthesisError> {
+        let (length, payload) = encoded
+            .split_first()
+            .expect("`encoded` should contain the length");
+
+        // `is_length[i]` is set if and only if `length == i`. Exactly one of
+        // these must be set, which means that `length <= max_len`.
+        let is_length = (0..=payload.len())
+            .map(|i| length.is_eq(&Self::constant(F::from(i as u64))))
+            .collect::<Result<Vec<_>, _>>()?;
+        is_length
+            .iter()
+            .fold(Self::zero(), |acc, b| acc + Self::from(b.clone()))
+            .enforce_equal(&Self::one())?;
+
+        // After processing position `i`, `is_active` is set if and only if
+        // `i < length`.
+        let mut is_active = Boolean::TRUE;
+        for (value, is_length) in payload.iter().zip(&is_length) {
+            is_active &= !is_length;
+            value.conditional_enforce_equal(&Self::zero(), &!&is_active)?;
+        }
+        Ok((length.clone(), payload.to_vec()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{alloc::AllocVar, fields::fp::FpVar, R1CSVar};
+    use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+    use ark_test_curves::bls12_381::Fr;
+
+    const MAX_LEN: usize = 4;
+
+    #[test]
+    fn round_trip() -> Result<(), SynthesisError> {
+        for len in 0..=MAX_LEN {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let values = (0..len as u64)
+                .map(|i| Fr::from(i + 10))
+                .collect::<Vec<_>>();
+            let values_var = Vec::<FpVar<Fr>>::new_witness(cs.clone(), || Ok(values.clone()))?;
+            let encoded = FpVar::encode_with_length(&values_var, MAX_LEN)?;
+            assert_eq!(encoded.len(), MAX_LEN + 1);
+
+            let encoded = Vec::<FpVar<Fr>>::new_input(cs.clone(), || encoded.value())?;
+            let (length, payload) = FpVar::decode_with_length(&encoded)?;
+            assert_eq!(length.value()?, Fr::from(len as u64));
+            assert_eq!(payload.len(), MAX_LEN);
+            assert_eq!(payload[..len].value()?, values);
+            assert!(payload[len..]
+                .iter()
+                .all(|v| v.value().unwrap() == Fr::from(0u64)));
+            assert!(cs.is_satisfied().unwrap());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn malformed() -> Result<(), SynthesisError> {
+        let encodings = [
+            // Non-zero padding.
+            [2i64, 10, 11, 0, 12],
+            // The length exceeds `MAX_LEN`.
+            [5, 10, 11, 12, 13],
+            // The length is "negative".
+            [-1, 0, 0, 0, 0],
+        ];
+        for encoding in encodings {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let encoding = encoding.map(Fr::from);
+            let encoded = Vec::<FpVar<Fr>>::new_witness(cs.clone(), || Ok(encoding))?;
+            let _ = FpVar::decode_with_length(&encoded)?;
+            assert!(!cs.is_satisfied().unwrap());
+        }
+        Ok(())
+    }
+}
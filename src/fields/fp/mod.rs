@@ -14,7 +14,17 @@ use crate::{
 };
 use ark_std::iter::Sum;
 
+mod affine;
 mod cmp;
+mod congruence;
+mod first_index;
+mod length_prefix;
+mod ntt;
+mod signed;
+mod subset_sum;
+mod two_adic;
+mod vector_commitment;
+mod weighted_sum;
 
 /// Represents a variable in the constraint system whose
 /// value can be an arbitrary field element.
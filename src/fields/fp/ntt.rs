@@ -0,0 +1,60 @@
+use ark_ff::PrimeField;
+use ark_relations::r1cs::SynthesisError;
+
+use crate::fields::fp::FpVar;
+
+impl<F: PrimeField> FpVar<F> {
+    /// Computes the Cooley-Tukey butterfly `(a + twiddle * b, a - twiddle * b)`.
+    ///
+    /// The product `twiddle * b` is shared by both outputs, so this requires
+    /// *one* constraint, or none if `twiddle` or `b` is a constant.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn ntt_butterfly(
+        a: &Self,
+        b: &Self,
+        twiddle: &Self,
+    ) -> Result<(Self, Self), SynthesisError> {
+        let twiddled_b = twiddle * b;
+        Ok((a + &twiddled_b, a - &twiddled_b))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        alloc::AllocVar,
+        fields::{fp::FpVar, FieldVar},
+        R1CSVar,
+    };
+    use ark_poly::{EvaluationDomain, Radix2EvaluationDomain};
+    use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn ntt_butterfly() -> Result<(), SynthesisError> {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let input = [3u64, 1, 4, 1].map(Fr::from);
+        let domain = Radix2EvaluationDomain::<Fr>::new(4).unwrap();
+        let expected = domain.fft(&input);
+
+        let x = Vec::<FpVar<Fr>>::new_witness(cs.clone(), || Ok(input))?;
+        let one = FpVar::one();
+        let w = FpVar::new_witness(cs.clone(), || Ok(domain.element(1)))?;
+
+        // The inputs of the first stage are in bit-reversed order.
+        let (e0, e1) = FpVar::ntt_butterfly(&x[0], &x[2], &one)?;
+        let (o0, o1) = FpVar::ntt_butterfly(&x[1], &x[3], &one)?;
+        let (y0, y2) = FpVar::ntt_butterfly(&e0, &o0, &one)?;
+        let (y1, y3) = FpVar::ntt_butterfly(&e1, &o1, &w)?;
+        // Only the butterfly with a variable twiddle requires a constraint.
+        assert_eq!(cs.num_constraints(), 1);
+
+        let output = [y0, y1, y2, y3]
+            .iter()
+            .map(|y| y.value())
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(output, expected);
+        assert!(cs.is_satisfied().unwrap());
+        Ok(())
+    }
+}
@@ -0,0 +1,165 @@
+use ark_ff::PrimeField;
+use ark_relations::r1cs::SynthesisError;
+
+use crate::{boolean::Boolean, fields::fp::FpVar, prelude::*, Vec};
+
+impl<F: PrimeField> FpVar<F> {
+    /// Interprets `bits` as the *little-endian* two's-complement
+    /// representation of a signed `N`-bit integer `v`, where `N = bits.len()`,
+    /// and outputs the field representative of `v`.
+    ///
+    /// That is, the last bit is the sign bit, and the output is
+    /// `unsigned(bits) - 2^N * sign`, so that a negative `v` is represented by
+    /// `F::MODULUS - |v|`.
+    ///
+    /// This *does not* create any new variables or constraints.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `bits` is empty, or if `N >= F::MODULUS_BIT_SIZE`.
+    #[tracing::instrument(target = "r1cs", skip(bits))]
+    pub fn from_signed_bits(bits: &[Boolean<F>]) -> Result<Self, SynthesisError> {
+        assert!(bits.len() < F::MODULUS_BIT_SIZE as usize);
+        let (sign, _) = bits.split_last().expect("`bits` should not be empty");
+        let unsigned = Boolean::le_bits_to_fp(bits)?;
+        let sign_weight = F::from(2u64).pow([bits.len() as u64]);
+        Ok(unsigned - Self::from(sign.clone()) * sign_weight)
+    }
+
+    /// Enforces that `self` is the field representative of the signed integer
+    /// whose *little-endian* two's-complement representation is `bits`.
+    ///
+    /// See [`FpVar::from_signed_bits`] for details.
+    ///
+    /// This requires *one* constraint.
+    #[tracing::instrument(target = "r1cs", skip(bits))]
+    pub fn enforce_equals_signed_bits(&self, bits: &[Boolean<F>]) -> Result<(), SynthesisError> {
+        self.enforce_equal(&Self::from_signed_bits(bits)?)
+    }
+
+    /// Enforces that `self` is the field representative of a signed
+    /// `bit_width`-bit integer `v`, i.e., that `v` lies in
+    /// `[-2^(bit_width - 1), 2^(bit_width - 1))`, and outputs the
+    /// *little-endian* bits of `v + 2^(bit_width - 1)`. The most significant
+    /// of these bits is set if and only if `v` is non-negative.
+    ///
+    /// This requires `bit_width + 1` constraints.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `bit_width == 0` or
+    /// `bit_width >= F::MODULUS_BIT_SIZE`.
+    pub(crate) fn enforce_signed_range(
+        &self,
+        bit_width: usize,
+    ) -> Result<Vec<Boolean<F>>, SynthesisError> {
+        assert!(bit_width > 0);
+        // `v` lies in `[-2^(bit_width - 1), 2^(bit_width - 1))` if and only if
+        // `v + 2^(bit_width - 1)` lies in `[0, 2^bit_width)`.
+        let offset = F::from(2u64).pow([bit_width as u64 - 1]);
+        let (bits, _) = (self + offset).to_bits_le_with_top_bits_zero(bit_width)?;
+        Ok(bits)
+    }
+
+    /// Interprets `self` as a signed `bit_width`-bit integer `v`, and outputs
+    /// the sign bit of `v` (i.e., `Boolean::TRUE` if `v` is negative) along
+    /// with the magnitude `|v|`. Additionally, this enforces that `v` is
+    /// non-zero.
+    ///
+    /// The constraint system is unsatisfiable if `v` does not lie in
+    /// `[-2^(bit_width - 1), 2^(bit_width - 1))`, or if `v` is zero.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `bit_width == 0` or
+    /// `bit_width >= F::MODULUS_BIT_SIZE`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn nonzero_sign(&self, bit_width: usize) -> Result<(Boolean<F>, Self), SynthesisError> {
+        assert!(bit_width > 0);
+        self.enforce_not_equal(&Self::zero())?;
+        let bits = self.enforce_signed_range(bit_width)?;
+        let is_negative = !&bits[bit_width - 1];
+        let magnitude = is_negative.select(&self.negate()?, self)?;
+        Ok((is_negative, magnitude))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{alloc::AllocVar, boolean::Boolean, fields::fp::FpVar, R1CSVar};
+    use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+    use ark_test_curves::bls12_381::Fr;
+
+    const VALUES: [i16; 9] = [0, 1, -1, 2, -2, 12345, -12345, i16::MAX, i16::MIN];
+
+    fn signed_bits(v: i16) -> Vec<bool> {
+        (0..16).map(|i| ((v as u16) >> i) & 1 == 1).collect()
+    }
+
+    #[test]
+    fn from_signed_bits() -> Result<(), SynthesisError> {
+        for v in VALUES {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let bits = Vec::<Boolean<Fr>>::new_witness(cs.clone(), || Ok(signed_bits(v)))?;
+            let result = FpVar::from_signed_bits(&bits)?;
+            assert_eq!(result.value()?, Fr::from(v));
+            if v < 0 {
+                assert_eq!(result.value()?, -Fr::from(v.unsigned_abs()));
+            }
+
+            let bits = Vec::<Boolean<Fr>>::new_constant(cs.clone(), signed_bits(v))?;
+            let result = FpVar::from_signed_bits(&bits)?;
+            assert!(result.is_constant());
+            assert_eq!(result.value()?, Fr::from(v));
+            assert!(cs.is_satisfied().unwrap());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn enforce_equals_signed_bits() -> Result<(), SynthesisError> {
+        for v in VALUES {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let bits = Vec::<Boolean<Fr>>::new_witness(cs.clone(), || Ok(signed_bits(v)))?;
+            let value = FpVar::new_witness(cs.clone(), || Ok(Fr::from(v)))?;
+            value.enforce_equals_signed_bits(&bits)?;
+            assert!(cs.is_satisfied().unwrap());
+        }
+
+        // The unsigned interpretation of a negative value must be rejected.
+        for v in VALUES.into_iter().filter(|v| *v < 0) {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let bits = Vec::<Boolean<Fr>>::new_witness(cs.clone(), || Ok(signed_bits(v)))?;
+            let value = FpVar::new_witness(cs.clone(), || Ok(Fr::from(v as u16)))?;
+            value.enforce_equals_signed_bits(&bits)?;
+            assert!(!cs.is_satisfied().unwrap());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn nonzero_sign() -> Result<(), SynthesisError> {
+        for v in VALUES.into_iter().filter(|v| *v != 0) {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let value = FpVar::new_witness(cs.clone(), || Ok(Fr::from(v)))?;
+            let (is_negative, magnitude) = value.nonzero_sign(16)?;
+            assert_eq!(is_negative.value()?, v < 0);
+            assert_eq!(magnitude.value()?, Fr::from(v.unsigned_abs()));
+            assert!(cs.is_satisfied().unwrap());
+        }
+
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let zero = FpVar::new_witness(cs.clone(), || Ok(Fr::from(0u64)))?;
+        let _ = zero.nonzero_sign(16)?;
+        assert!(!cs.is_satisfied().unwrap());
+
+        // Values outside of the signed 16-bit range must be rejected.
+        for v in [i16::MAX as i64 + 1, i16::MIN as i64 - 1] {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let value = FpVar::new_witness(cs.clone(), || Ok(Fr::from(v)))?;
+            let _ = value.nonzero_sign(16)?;
+            assert!(!cs.is_satisfied().unwrap());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use ark_ff::PrimeField;
+use ark_relations::r1cs::SynthesisError;
+
+use crate::{boolean::Boolean, fields::fp::FpVar, prelude::*};
+
+impl<F: PrimeField> FpVar<F> {
+    /// Enforces that the elements of `values` that are selected by `mask` sum
+    /// to `target`, i.e., that `Σᵢ maskᵢ * valuesᵢ == target`.
+    ///
+    /// This requires one constraint for every term where both `maskᵢ` and
+    /// `valuesᵢ` are variables, and one constraint for the final equality
+    /// check.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `values.len() != mask.len()`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn enforce_subset_sum(
+        values: &[Self],
+        mask: &[Boolean<F>],
+        target: &Self,
+    ) -> Result<(), SynthesisError> {
+        assert_eq!(values.len(), mask.len());
+        let mut sum = Self::zero();
+        for (value, bit) in values.iter().zip(mask) {
+            sum += Self::from(bit.clone()) * value;
+        }
+        sum.enforce_equal(target)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{alloc::AllocVar, boolean::Boolean, fields::fp::FpVar};
+    use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+    use ark_test_curves::bls12_381::Fr;
+
+    fn check(mask: &[bool], target: u64) -> Result<bool, SynthesisError> {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let values = [3u64, 5, 7, 11]
+            .iter()
+            .map(|v| FpVar::new_witness(cs.clone(), || Ok(Fr::from(*v))))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mask = Vec::<Boolean<Fr>>::new_witness(cs.clone(), || Ok(mask))?;
+        let target = FpVar::new_input(cs.clone(), || Ok(Fr::from(target)))?;
+        FpVar::enforce_subset_sum(&values, &mask, &target)?;
+        // One constraint per term, and one for the equality check.
+        assert_eq!(cs.num_constraints(), 4 + 4 + 1);
+        cs.is_satisfied()
+    }
+
+    #[test]
+    fn enforce_subset_sum() -> Result<(), SynthesisError> {
+        assert!(check(&[true, false, true, true], 21)?);
+        assert!(check(&[false, true, true, false], 12)?);
+        assert!(!check(&[true, false, true, true], 20)?);
+        assert!(!check(&[true, true, true, true], 21)?);
+
+        // The empty subset sums to zero.
+        assert!(check(&[false; 4], 0)?);
+        assert!(!check(&[false; 4], 3)?);
+        Ok(())
+    }
+}
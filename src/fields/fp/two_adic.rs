@@ -0,0 +1,67 @@
+use ark_ff::PrimeField;
+use ark_relations::r1cs::SynthesisError;
+
+use crate::{boolean::Boolean, fields::fp::FpVar, prelude::*};
+
+impl<F: PrimeField> FpVar<F> {
+    /// Outputs the two-adic valuation of `self`, i.e., the number of trailing
+    /// zero bits of `self`, where `self` is an integer that fits in
+    /// `bit_width` bits. If `self` is zero, this outputs `bit_width`.
+    ///
+    /// The constraint system is unsatisfiable if `self >= 2^bit_width`.
+    ///
+    /// This decomposes `self` into bits, and then uses a priority encoder that
+    /// counts the prefixes of the bit decomposition that are all zero. In total,
+    /// this requires roughly `2 * bit_width` constraints.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn two_adic_valuation(&self, bit_width: usize) -> Result<Self, SynthesisError> {
+        let (bits, _) = self.to_bits_le_with_top_bits_zero(bit_width)?;
+        let mut all_zero_so_far = Boolean::TRUE;
+        let mut valuation = Self::zero();
+        for bit in &bits {
+            all_zero_so_far &= !bit;
+            valuation += Self::from(all_zero_so_far.clone());
+        }
+        Ok(valuation)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        alloc::{AllocVar, AllocationMode},
+        fields::fp::FpVar,
+        test_utils::modes,
+        R1CSVar,
+    };
+    use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn two_adic_valuation() -> Result<(), SynthesisError> {
+        let values = [0u32, 1, 2, 3, 4, 8, 12, 96, 1 << 20, 1 << 31, u32::MAX];
+        for mode in modes() {
+            for v in values {
+                let cs = ConstraintSystem::<Fr>::new_ref();
+                let v_var = FpVar::new_variable(cs.clone(), || Ok(Fr::from(v)), mode)?;
+                let valuation = v_var.two_adic_valuation(32)?;
+                assert_eq!(valuation.value()?, Fr::from(v.trailing_zeros()));
+                if mode == AllocationMode::Constant {
+                    assert!(valuation.is_constant());
+                } else {
+                    assert!(cs.is_satisfied().unwrap());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn two_adic_valuation_out_of_range() -> Result<(), SynthesisError> {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let v_var = FpVar::new_witness(cs.clone(), || Ok(Fr::from(1u64 << 32)))?;
+        let _ = v_var.two_adic_valuation(32)?;
+        assert!(!cs.is_satisfied().unwrap());
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use ark_ff::PrimeField;
+use ark_relations::r1cs::SynthesisError;
+
+use crate::{boolean::Boolean, fields::fp::FpVar, prelude::*};
+
+impl<F: PrimeField> FpVar<F> {
+    /// Verifies an opening of the vector commitment `commitment` to `value` at
+    /// the position represented by `index_bits`, and outputs `Boolean::TRUE`
+    /// if the opening is valid.
+    ///
+    /// The opening is valid if `hasher(full_vector) == commitment` and
+    /// `full_vector[index] == value`. Like
+    /// [`CondSelectGadget::conditionally_select_power_of_two_vector`],
+    /// `index_bits` is the *big-endian* representation of `index`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `full_vector.len() != 2^index_bits.len()`.
+    #[tracing::instrument(target = "r1cs", skip(hasher))]
+    pub fn verify_vector_opening(
+        commitment: &Self,
+        index_bits: &[Boolean<F>],
+        value: &Self,
+        full_vector: &[Self],
+        hasher: impl Fn(&[Self]) -> Result<Self, SynthesisError>,
+    ) -> Result<Boolean<F>, SynthesisError> {
+        let commitment_matches = hasher(full_vector)?.is_eq(commitment)?;
+        let selected = Self::conditionally_select_power_of_two_vector(index_bits, full_vector)?;
+        let value_matches = selected.is_eq(value)?;
+        Ok(commitment_matches & value_matches)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        alloc::AllocVar,
+        boolean::Boolean,
+        fields::{fp::FpVar, FieldVar},
+        R1CSVar,
+    };
+    use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+    use ark_test_curves::bls12_381::Fr;
+
+    fn mock_hasher(values: &[FpVar<Fr>]) -> Result<FpVar<Fr>, SynthesisError> {
+        Ok(values
+            .iter()
+            .fold(FpVar::zero(), |acc, v| acc * Fr::from(7u64) + v))
+    }
+
+    #[test]
+    fn verify_vector_opening() -> Result<(), SynthesisError> {
+        let vector = (0..8u64).map(|i| Fr::from(i * i + 3)).collect::<Vec<_>>();
+        let commitment = {
+            let constants = vector
+                .iter()
+                .copied()
+                .map(FpVar::Constant)
+                .collect::<Vec<_>>();
+            mock_hasher(&constants)?.value()?
+        };
+        // The big-endian representations of 5 and 6.
+        let five = [true, false, true];
+        let six = [true, true, false];
+
+        let cases = [
+            (commitment, five, vector[5], true),
+            (commitment, five, vector[6], false),
+            (commitment, six, vector[5], false),
+            (commitment + Fr::from(1u64), five, vector[5], false),
+        ];
+        for (commitment, index, value, expected) in cases {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let commitment = FpVar::new_input(cs.clone(), || Ok(commitment))?;
+            let index_bits = Vec::<Boolean<Fr>>::new_witness(cs.clone(), || Ok(index))?;
+            let value = FpVar::new_witness(cs.clone(), || Ok(value))?;
+            let full_vector = Vec::<FpVar<Fr>>::new_witness(cs.clone(), || Ok(vector.clone()))?;
+            let is_valid = FpVar::verify_vector_opening(
+                &commitment,
+                &index_bits,
+                &value,
+                &full_vector,
+                mock_hasher,
+            )?;
+            assert_eq!(is_valid.value()?, expected);
+            assert!(cs.is_satisfied().unwrap());
+        }
+        Ok(())
+    }
+}
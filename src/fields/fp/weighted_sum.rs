@@ -0,0 +1,107 @@
+use ark_ff::PrimeField;
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError, Variable};
+
+use crate::fields::fp::{AllocatedFp, FpVar};
+
+impl<F: PrimeField> FpVar<F> {
+    /// Outputs `sum_i weights[i] * values[i]`.
+    ///
+    /// Since the weights are constants, this scales the linear combination of
+    /// every value by its weight, and then sums the results. This does not
+    /// create any constraints, and only creates one linear combination.
+    ///
+    /// # Errors
+    ///
+    /// This function returns `SynthesisError::Unsatisfiable` if
+    /// `values.len() != weights.len()`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn weighted_sum(values: &[Self], weights: &[F]) -> Result<Self, SynthesisError> {
+        if values.len() != weights.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        let mut cs = ConstraintSystemRef::None;
+        let mut constant = F::zero();
+        let mut value = Some(F::zero());
+        let mut lc = lc!();
+        for (v, w) in values.iter().zip(weights) {
+            match v {
+                Self::Constant(c) => constant += *c * w,
+                Self::Var(v) => {
+                    cs = cs.or(v.cs.clone());
+                    value = value.zip(v.value).map(|(acc, v)| acc + v * w);
+                    lc = lc + (*w, v.variable);
+                },
+            }
+        }
+
+        if cs.is_none() {
+            return Ok(Self::Constant(constant));
+        }
+        let variable = cs.new_lc(lc + (constant, Variable::One))?;
+        Ok(AllocatedFp::new(value.map(|v| v + constant), variable, cs).into())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        alloc::AllocVar,
+        eq::EqGadget,
+        fields::{fp::FpVar, FieldVar},
+        R1CSVar,
+    };
+    use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+    use ark_std::UniformRand;
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn weighted_sum() -> Result<(), SynthesisError> {
+        let mut rng = ark_std::test_rng();
+        for num_constants in 0..=3 {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let values = (0..5)
+                .map(|i| {
+                    let value = Fr::rand(&mut rng);
+                    if i < num_constants {
+                        FpVar::new_constant(cs.clone(), value)
+                    } else {
+                        FpVar::new_witness(cs.clone(), || Ok(value))
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            let weights = (0..5).map(|_| Fr::rand(&mut rng)).collect::<Vec<_>>();
+            let num_witnesses = cs.num_witness_variables();
+
+            let sum = FpVar::weighted_sum(&values, &weights)?;
+            assert_eq!(cs.num_constraints(), 0);
+            assert_eq!(cs.num_witness_variables(), num_witnesses);
+
+            let expected = values
+                .iter()
+                .zip(&weights)
+                .fold(FpVar::zero(), |acc, (v, w)| acc + v * *w);
+            assert_eq!(sum.value()?, expected.value()?);
+            sum.enforce_equal(&expected)?;
+            assert!(cs.is_satisfied().unwrap());
+        }
+
+        let constants = [1u64, 2, 3].map(|v| FpVar::Constant(Fr::from(v)));
+        let weights = [4u64, 5, 6].map(Fr::from);
+        let sum = FpVar::weighted_sum(&constants, &weights)?;
+        assert!(sum.is_constant());
+        assert_eq!(sum.value()?, Fr::from(32u64));
+
+        assert!(FpVar::<Fr>::weighted_sum(&[], &[])?.is_constant());
+        Ok(())
+    }
+
+    #[test]
+    fn weighted_sum_length_mismatch() {
+        let values = [FpVar::Constant(Fr::from(1u64))];
+        let weights = [Fr::from(1u64), Fr::from(2u64)];
+        assert!(matches!(
+            FpVar::weighted_sum(&values, &weights),
+            Err(SynthesisError::Unsatisfiable)
+        ));
+    }
+}
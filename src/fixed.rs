@@ -0,0 +1,179 @@
+use ark_ff::PrimeField;
+use ark_relations::r1cs::{ConstraintSystemRef, Namespace, SynthesisError};
+use core::borrow::Borrow;
+
+use crate::{fields::fp::FpVar, prelude::*};
+
+/// Represents a signed fixed-point number with `FRAC_BITS` fractional bits.
+///
+/// The number `x` is stored as its scaled integer representative
+/// `x * 2^FRAC_BITS`, which is a signed `BITS`-bit integer embedded into `F`.
+/// That is, a negative representative `-r` is stored as `F::MODULUS - r`.
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct Fixed<const BITS: usize, const FRAC_BITS: usize, F: PrimeField> {
+    raw: FpVar<F>,
+}
+
+impl<const BITS: usize, const FRAC_BITS: usize, F: PrimeField> R1CSVar<F>
+    for Fixed<BITS, FRAC_BITS, F>
+{
+    type Value = F;
+
+    fn cs(&self) -> ConstraintSystemRef<F> {
+        self.raw.cs()
+    }
+
+    fn value(&self) -> Result<Self::Value, SynthesisError> {
+        self.raw.value()
+    }
+}
+
+impl<const BITS: usize, const FRAC_BITS: usize, F: PrimeField> Fixed<BITS, FRAC_BITS, F> {
+    /// Constructs `Self` from the scaled integer representative `raw`,
+    /// enforcing that `raw` is a signed `BITS`-bit integer.
+    ///
+    /// This requires `BITS + 1` constraints.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `BITS == 0`, if `BITS >= F::MODULUS_BIT_SIZE`,
+    /// or if `FRAC_BITS > BITS`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn from_fp(raw: FpVar<F>) -> Result<Self, SynthesisError> {
+        assert!(BITS >= 1 && FRAC_BITS <= BITS);
+        let _ = raw.enforce_signed_range(BITS)?;
+        Ok(Self { raw })
+    }
+
+    /// Constructs `Self` from the scaled integer representative `raw` without
+    /// checking that `raw` is a signed `BITS`-bit integer.
+    ///
+    /// This *does not* create any new variables or constraints.
+    pub fn from_fp_unchecked(raw: FpVar<F>) -> Self {
+        Self { raw }
+    }
+
+    /// Returns the scaled integer representative of `self`.
+    pub fn as_fp(&self) -> &FpVar<F> {
+        &self.raw
+    }
+
+    /// Outputs `self * other`, rounded to the nearest multiple of
+    /// `2^-FRAC_BITS`. Ties are rounded up, i.e., towards positive infinity.
+    ///
+    /// The rounding is performed by adding half an LSB (`2^(FRAC_BITS - 1)`)
+    /// to the product of the representatives and then truncating the lowest
+    /// `FRAC_BITS` bits. The result is enforced to be a signed `BITS`-bit
+    /// integer, so the constraint system is unsatisfiable if the product
+    /// overflows.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `BITS == 0` or
+    /// `2 * BITS > F::MODULUS_BIT_SIZE - 1`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn mul(&self, other: &Self) -> Result<Self, SynthesisError> {
+        assert!(BITS >= 1 && 2 * BITS <= F::MODULUS_BIT_SIZE as usize - 1);
+        let half = if FRAC_BITS == 0 {
+            F::zero()
+        } else {
+            two_to_the::<F>(FRAC_BITS - 1)
+        };
+        // Since both representatives lie in `[-2^(BITS - 1), 2^(BITS - 1))`,
+        // adding `2^(2 * BITS - 1)` to the rounded product yields a
+        // non-negative integer smaller than `2^(2 * BITS)`. Moreover, the offset
+        // is a multiple of `2^FRAC_BITS`, so it does not affect the truncation.
+        let offset_bits = 2 * BITS - 1;
+        let shifted = &self.raw * &other.raw + (half + two_to_the::<F>(offset_bits));
+        let (bits, _) = shifted.to_bits_le_with_top_bits_zero(2 * BITS)?;
+        let truncated = Boolean::le_bits_to_fp(&bits[FRAC_BITS..])?;
+        Self::from_fp(truncated - two_to_the::<F>(offset_bits - FRAC_BITS))
+    }
+}
+
+impl<const BITS: usize, const FRAC_BITS: usize, F: PrimeField> AllocVar<F, F>
+    for Fixed<BITS, FRAC_BITS, F>
+{
+    /// Allocates the scaled integer representative of a fixed-point number,
+    /// and enforces that it is a signed `BITS`-bit integer.
+    fn new_variable<T: Borrow<F>>(
+        cs: impl Into<Namespace<F>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        FpVar::new_variable(cs, f, mode).and_then(Self::from_fp)
+    }
+}
+
+fn two_to_the<F: PrimeField>(exp: usize) -> F {
+    F::from(2u64).pow([exp as u64])
+}
+
+#[cfg(test)]
+mod test {
+    use super::Fixed;
+    use crate::{alloc::AllocVar, R1CSVar};
+    use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+    use ark_test_curves::bls12_381::Fr;
+
+    type Fixed16<F> = Fixed<16, 8, F>;
+
+    fn reference_mul(a: i64, b: i64) -> i64 {
+        // `>>` on signed integers is an arithmetic shift, i.e., it rounds
+        // towards negative infinity.
+        (a * b + (1 << 7)) >> 8
+    }
+
+    #[test]
+    fn mul() -> Result<(), SynthesisError> {
+        let cases: [(i64, i64); 10] = [
+            // 1.5 * 2.25 is exact.
+            (384, 576),
+            // 1/256 * 0.5 rounds up.
+            (1, 128),
+            // 1/256 * 127/256 rounds down.
+            (1, 127),
+            // -1/256 * 0.5 rounds up to zero.
+            (-1, 128),
+            // -1/256 * 129/256 rounds down to -1/256.
+            (-1, 129),
+            (-300, 700),
+            (-512, -640),
+            (0, 1000),
+            (32767, 256),
+            (-32768, 256),
+        ];
+        for (a, b) in cases {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let a_var = Fixed16::new_witness(cs.clone(), || Ok(Fr::from(a)))?;
+            let b_var = Fixed16::new_witness(cs.clone(), || Ok(Fr::from(b)))?;
+            let c_var = a_var.mul(&b_var)?;
+            assert_eq!(c_var.value()?, Fr::from(reference_mul(a, b)));
+            assert!(cs.is_satisfied().unwrap());
+
+            let c_const = Fixed16::new_constant(cs.clone(), Fr::from(a))?
+                .mul(&Fixed16::new_constant(cs.clone(), Fr::from(b))?)?;
+            assert_eq!(c_const.value()?, Fr::from(reference_mul(a, b)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn mul_overflow() -> Result<(), SynthesisError> {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let a_var = Fixed16::new_witness(cs.clone(), || Ok(Fr::from(32767i64)))?;
+        let b_var = Fixed16::new_witness(cs.clone(), || Ok(Fr::from(-32768i64)))?;
+        let _ = a_var.mul(&b_var)?;
+        assert!(!cs.is_satisfied().unwrap());
+        Ok(())
+    }
+
+    #[test]
+    fn out_of_range() -> Result<(), SynthesisError> {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let _ = Fixed16::new_witness(cs.clone(), || Ok(Fr::from(32768i64)))?;
+        assert!(!cs.is_satisfied().unwrap());
+        Ok(())
+    }
+}
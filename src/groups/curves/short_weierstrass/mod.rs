@@ -979,14 +979,16 @@ where
 mod test_sw_curve {
     use crate::{
         alloc::AllocVar,
+        boolean::Boolean,
         convert::ToBitsGadget,
         eq::EqGadget,
         fields::{emulated_fp::EmulatedFpVar, fp::FpVar},
         groups::{curves::short_weierstrass::ProjectiveVar, CurveVar},
+        R1CSVar,
     };
     use ark_ec::{
         short_weierstrass::{Projective, SWCurveConfig},
-        CurveGroup,
+        CurveGroup, PrimeGroup,
     };
     use ark_ff::PrimeField;
     use ark_relations::r1cs::{ConstraintSystem, Result};
@@ -1031,4 +1033,177 @@ mod test_sw_curve {
         assert!(zero_point_scalar_mul_satisfied::<ark_mnt6_298::G1Projective>().unwrap());
         assert!(zero_point_scalar_mul_satisfied::<ark_bn254::G1Projective>().unwrap());
     }
+
+    fn conditional_add_satisfied<G>() -> Result<bool>
+    where
+        G: CurveGroup,
+        G::BaseField: PrimeField,
+        G::Config: SWCurveConfig,
+    {
+        let mut rng = ark_std::test_rng();
+
+        let cs = ConstraintSystem::new_ref();
+        let a = Projective::<G::Config>::rand(&mut rng);
+        let b = Projective::<G::Config>::rand(&mut rng);
+
+        let a_var =
+            ProjectiveVar::<G::Config, FpVar<G::BaseField>>::new_witness(cs.clone(), || Ok(a))?;
+        let b_var =
+            ProjectiveVar::<G::Config, FpVar<G::BaseField>>::new_witness(cs.clone(), || Ok(b))?;
+        for (cond, expected) in [(true, a + b), (false, a)] {
+            let cond = Boolean::new_witness(cs.clone(), || Ok(cond))?;
+            let sum = a_var.conditional_add(&cond, &b_var)?;
+            assert_eq!(sum.value()?, expected);
+
+            let expected_var =
+                ProjectiveVar::<G::Config, FpVar<G::BaseField>>::new_input(cs.clone(), || {
+                    Ok(expected)
+                })?;
+            sum.enforce_equal(&expected_var)?;
+        }
+
+        // If `cond` is false, the identity is selected as the addend.
+        let cond = Boolean::new_witness(cs.clone(), || Ok(false))?;
+        let zero = ProjectiveVar::<G::Config, FpVar<G::BaseField>>::zero();
+        let sum = zero.conditional_add(&cond, &b_var)?;
+        assert!(sum.value()?.is_zero());
+        sum.is_zero()?.enforce_equal(&Boolean::TRUE)?;
+
+        cs.is_satisfied()
+    }
+
+    #[test]
+    fn test_conditional_add() {
+        assert!(conditional_add_satisfied::<ark_bls12_381::G1Projective>().unwrap());
+        assert!(conditional_add_satisfied::<ark_pallas::Projective>().unwrap());
+        assert!(conditional_add_satisfied::<ark_bn254::G1Projective>().unwrap());
+    }
+
+    fn homomorphic_sum_satisfied<G>(
+        a: Projective<G::Config>,
+        b: Projective<G::Config>,
+        c: Projective<G::Config>,
+    ) -> Result<bool>
+    where
+        G: CurveGroup,
+        G::BaseField: PrimeField,
+        G::Config: SWCurveConfig,
+    {
+        let cs = ConstraintSystem::new_ref();
+        let a = ProjectiveVar::<G::Config, FpVar<G::BaseField>>::new_witness(cs.clone(), || Ok(a))?;
+        let b = ProjectiveVar::<G::Config, FpVar<G::BaseField>>::new_witness(cs.clone(), || Ok(b))?;
+        let c = ProjectiveVar::<G::Config, FpVar<G::BaseField>>::new_input(cs.clone(), || Ok(c))?;
+        ProjectiveVar::enforce_homomorphic_sum(&c, &a, &b)?;
+        cs.is_satisfied()
+    }
+
+    fn test_homomorphic_sum_for<G>() -> Result<()>
+    where
+        G: CurveGroup,
+        G::BaseField: PrimeField,
+        G::Config: SWCurveConfig,
+    {
+        let mut rng = ark_std::test_rng();
+        let a = Projective::<G::Config>::rand(&mut rng);
+        let b = Projective::<G::Config>::rand(&mut rng);
+        let offset = Projective::<G::Config>::rand(&mut rng);
+        let zero = Projective::<G::Config>::zero();
+
+        assert!(homomorphic_sum_satisfied::<G>(a, b, a + b)?);
+        assert!(homomorphic_sum_satisfied::<G>(zero, b, b)?);
+        assert!(homomorphic_sum_satisfied::<G>(a, zero, a)?);
+        assert!(homomorphic_sum_satisfied::<G>(a, -a, zero)?);
+        assert!(homomorphic_sum_satisfied::<G>(zero, zero, zero)?);
+
+        assert!(!homomorphic_sum_satisfied::<G>(a, b, a + b + offset)?);
+        assert!(!homomorphic_sum_satisfied::<G>(a, b, zero)?);
+        assert!(!homomorphic_sum_satisfied::<G>(zero, b, zero)?);
+        assert!(!homomorphic_sum_satisfied::<G>(a, -a, a)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_homomorphic_sum() {
+        test_homomorphic_sum_for::<ark_bls12_381::G1Projective>().unwrap();
+        test_homomorphic_sum_for::<ark_pallas::Projective>().unwrap();
+        test_homomorphic_sum_for::<ark_bn254::G1Projective>().unwrap();
+    }
+
+    fn mul_by_small_constant_satisfied<G>() -> Result<bool>
+    where
+        G: CurveGroup,
+        G::BaseField: PrimeField,
+        G::Config: SWCurveConfig,
+    {
+        let mut rng = ark_std::test_rng();
+
+        let cs = ConstraintSystem::new_ref();
+        let point = Projective::<G::Config>::rand(&mut rng);
+        let point_var =
+            ProjectiveVar::<G::Config, FpVar<G::BaseField>>::new_witness(cs.clone(), || Ok(point))?;
+        for k in [0u64, 1, 2, 3, 5, 7, 11, 15, 31, 100, 255, u64::MAX] {
+            let expected = point.mul_bigint([k]);
+            let result = point_var.mul_by_small_constant(k)?;
+            assert_eq!(result.value()?, expected);
+
+            let expected_var =
+                ProjectiveVar::<G::Config, FpVar<G::BaseField>>::new_input(cs.clone(), || {
+                    Ok(expected)
+                })?;
+            result.enforce_equal(&expected_var)?;
+        }
+
+        let constant = ProjectiveVar::<G::Config, FpVar<G::BaseField>>::constant(point);
+        let result = constant.mul_by_small_constant(5)?;
+        assert!(result.is_constant());
+        assert_eq!(result.value()?, point.mul_bigint([5]));
+
+        cs.is_satisfied()
+    }
+
+    #[test]
+    fn test_mul_by_small_constant() {
+        assert!(mul_by_small_constant_satisfied::<ark_bls12_381::G1Projective>().unwrap());
+        assert!(mul_by_small_constant_satisfied::<ark_pallas::Projective>().unwrap());
+        assert!(mul_by_small_constant_satisfied::<ark_bn254::G1Projective>().unwrap());
+    }
+
+    fn mul_by_small_constant_num_constraints<G>() -> Result<()>
+    where
+        G: CurveGroup,
+        G::BaseField: PrimeField,
+        G::Config: SWCurveConfig,
+    {
+        let mut rng = ark_std::test_rng();
+
+        let cs = ConstraintSystem::new_ref();
+        let point = Projective::<G::Config>::rand(&mut rng);
+        let point_var =
+            ProjectiveVar::<G::Config, FpVar<G::BaseField>>::new_witness(cs.clone(), || Ok(point))?;
+
+        // `3 * P` should be computed as `2P + P` rather than `4P - P`.
+        let num_constraints = cs.num_constraints();
+        let _ = point_var.mul_by_small_constant(3)?;
+        let chain_constraints = cs.num_constraints() - num_constraints;
+        let num_constraints = cs.num_constraints();
+        let _ = point_var.double()? + &point_var;
+        assert_eq!(chain_constraints, cs.num_constraints() - num_constraints);
+
+        // `11 * P` should be computed as `2(2(2P) + P) + P` rather than
+        // `2(2(4P - P)) - P`.
+        let num_constraints = cs.num_constraints();
+        let _ = point_var.mul_by_small_constant(11)?;
+        let chain_constraints = cs.num_constraints() - num_constraints;
+        let num_constraints = cs.num_constraints();
+        let _ = (point_var.double()?.double()? + &point_var).double()? + &point_var;
+        assert_eq!(chain_constraints, cs.num_constraints() - num_constraints);
+        Ok(())
+    }
+
+    #[test]
+    fn test_mul_by_small_constant_num_constraints() {
+        mul_by_small_constant_num_constraints::<ark_bls12_381::G1Projective>().unwrap();
+        mul_by_small_constant_num_constraints::<ark_pallas::Projective>().unwrap();
+        mul_by_small_constant_num_constraints::<ark_bn254::G1Projective>().unwrap();
+    }
 }
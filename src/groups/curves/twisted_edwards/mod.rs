@@ -378,6 +378,87 @@ where
         }
         Ok(ed_result.unwrap())
     }
+
+    /// Computes the windowed Pedersen hash of `bits` with respect to
+    /// `generators`, as in the Zcash Sapling construction.
+    ///
+    /// `bits` is split into chunks of three bits `(s_0, s_1, s_2)`, each of
+    /// which encodes the signed digit `(1 + s_0 + 2 * s_1) * (1 - 2 * s_2)`.
+    /// If `bits.len()` is not a multiple of three, the last chunk is padded
+    /// with zeros. Every `window_size` consecutive chunks form a segment, and
+    /// the `j`-th segment is the scalar for `generators[j]`, where the `i`-th
+    /// digit of the segment is weighted by `2^(4 * i)`.
+    ///
+    /// The digits are looked up in tables of precomputed multiples of the
+    /// generators via [`ThreeBitCondNegLookupGadget`], which is much cheaper
+    /// than conditionally adding a base for every bit.
+    ///
+    /// The additions within a segment are performed in Montgomery form, which
+    /// is only sound if no partial sum can equal `±` the next addend. The
+    /// scalar of a segment of `c` chunks is at most `4 * (2^(4c) - 1) / 15`
+    /// in absolute value, so we require it to be smaller than `(r - 1) / 2`,
+    /// where `r` is the order of the scalar field. Since only
+    /// `r > 2^(MODULUS_BIT_SIZE - 1)` is known, this holds whenever
+    /// `4 * window_size <= MODULUS_BIT_SIZE - 1`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `window_size == 0`, if `window_size` exceeds
+    /// `(P::ScalarField::MODULUS_BIT_SIZE - 1) / 4` (e.g., 62 for Jubjub), or
+    /// if `generators` has fewer than `ceil(bits.len() / (3 * window_size))`
+    /// elements.
+    #[tracing::instrument(target = "r1cs", skip(bits, generators))]
+    pub fn pedersen_hash(
+        bits: &[Boolean<BasePrimeField<P>>],
+        window_size: usize,
+        generators: &[TEProjective<P>],
+    ) -> Result<Self, SynthesisError> {
+        const CHUNK_SIZE: usize = 3;
+        let max_window_size = (P::ScalarField::MODULUS_BIT_SIZE as usize - 1) / 4;
+        assert!(window_size > 0);
+        assert!(
+            window_size <= max_window_size,
+            "window size exceeds the maximum of {max_window_size} for this curve"
+        );
+        if bits.is_empty() {
+            return Ok(Self::zero());
+        }
+
+        let chunks = bits
+            .chunks(CHUNK_SIZE)
+            .map(|chunk| {
+                let mut chunk = chunk.to_vec();
+                chunk.resize(CHUNK_SIZE, Boolean::FALSE);
+                chunk
+            })
+            .collect::<Vec<_>>();
+        let segments = chunks.chunks(window_size).collect::<Vec<_>>();
+        assert!(
+            segments.len() <= generators.len(),
+            "not enough generators for the message length"
+        );
+
+        // The `i`-th chunk of a segment is looked up in the multiples of
+        // `2^(4 * i) * generator`.
+        let bases = segments
+            .iter()
+            .zip(generators)
+            .map(|(segment, generator)| {
+                let mut base = *generator;
+                (0..segment.len())
+                    .map(|_| {
+                        let current = base;
+                        for _ in 0..4 {
+                            base = base.double();
+                        }
+                        current
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        Self::precomputed_base_3_bit_signed_digit_scalar_mul(&bases, &segments)
+    }
 }
 
 impl<P, F> R1CSVar<BasePrimeField<P>> for AffineVar<P, F>
@@ -936,3 +1017,93 @@ where
         Ok(x_bytes)
     }
 }
+
+#[cfg(test)]
+mod test_te_curve {
+    use crate::{
+        alloc::AllocVar,
+        boolean::Boolean,
+        fields::fp::FpVar,
+        groups::{curves::twisted_edwards::AffineVar, CurveVar},
+        R1CSVar,
+    };
+    use ark_ed_on_bls12_381::{EdwardsConfig, EdwardsProjective, Fq, Fr};
+    use ark_ff::AdditiveGroup;
+    use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+    use ark_std::{rand::Rng, UniformRand, Zero};
+
+    type EdwardsVar = AffineVar<EdwardsConfig, FpVar<Fq>>;
+
+    const WINDOW_SIZE: usize = 4;
+
+    fn native_pedersen_hash(bits: &[bool], generators: &[EdwardsProjective]) -> EdwardsProjective {
+        let mut result = EdwardsProjective::zero();
+        for (segment, generator) in bits.chunks(3 * WINDOW_SIZE).zip(generators) {
+            let mut base = *generator;
+            for chunk in segment.chunks(3) {
+                let bit = |i: usize| chunk.get(i).copied().unwrap_or(false) as u64;
+                let digit = base * Fr::from(1 + bit(0) + 2 * bit(1));
+                if bit(2) == 1 {
+                    result -= digit;
+                } else {
+                    result += digit;
+                }
+                for _ in 0..4 {
+                    base.double_in_place();
+                }
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn pedersen_hash() -> Result<(), SynthesisError> {
+        let mut rng = ark_std::test_rng();
+        // 100 bits are split into 34 chunks, i.e., 9 segments.
+        let generators = (0..9)
+            .map(|_| EdwardsProjective::rand(&mut rng))
+            .collect::<Vec<_>>();
+        let message = (0..100).map(|_| rng.gen()).collect::<Vec<bool>>();
+
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let bits = Vec::<Boolean<Fq>>::new_witness(cs.clone(), || Ok(message.as_slice()))?;
+        let num_constraints = cs.num_constraints();
+        let hash = EdwardsVar::pedersen_hash(&bits, WINDOW_SIZE, &generators)?;
+        let pedersen_constraints = cs.num_constraints() - num_constraints;
+        assert!(cs.is_satisfied().unwrap());
+        assert_eq!(hash.value()?, native_pedersen_hash(&message, &generators));
+
+        // Compare against conditionally adding a base for every bit.
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let bits = Vec::<Boolean<Fq>>::new_witness(cs.clone(), || Ok(message.as_slice()))?;
+        let num_constraints = cs.num_constraints();
+        let mut naive = EdwardsVar::zero();
+        for (segment, generator) in bits.chunks(3 * WINDOW_SIZE).zip(&generators) {
+            let mut base = *generator;
+            for bit in segment {
+                let sum = naive.clone() + base;
+                naive = bit.select(&sum, &naive)?;
+                base.double_in_place();
+            }
+        }
+        let naive_constraints = cs.num_constraints() - num_constraints;
+        assert!(cs.is_satisfied().unwrap());
+        assert!(pedersen_constraints < naive_constraints);
+        Ok(())
+    }
+
+    #[test]
+    fn pedersen_hash_empty() -> Result<(), SynthesisError> {
+        let hash = EdwardsVar::pedersen_hash(&[], WINDOW_SIZE, &[])?;
+        assert!(hash.value()?.is_zero());
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "window size exceeds the maximum")]
+    fn pedersen_hash_oversized_window() {
+        // The scalar field of Jubjub has 252 bits, so segments may contain at
+        // most 62 chunks.
+        let _ = EdwardsVar::pedersen_hash(&[], 63, &[]);
+    }
+}
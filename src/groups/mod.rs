@@ -2,6 +2,7 @@ use crate::{
     convert::{ToBitsGadget, ToBytesGadget},
     fields::emulated_fp::EmulatedFpVar,
     prelude::*,
+    Vec,
 };
 use ark_ff::PrimeField;
 use ark_relations::r1cs::{Namespace, SynthesisError};
@@ -92,6 +93,90 @@ pub trait CurveVar<C: CurveGroup, ConstraintF: PrimeField>:
     /// Coputes `-self`.
     fn negate(&self) -> Result<Self, SynthesisError>;
 
+    /// Computes `self + addend` if `cond` is `Boolean::TRUE`, and `self`
+    /// otherwise.
+    ///
+    /// This selects between `addend` and the identity *before* adding, so
+    /// that the same constraints are generated regardless of the value of
+    /// `cond`.
+    #[tracing::instrument(target = "r1cs")]
+    fn conditional_add(
+        &self,
+        cond: &Boolean<ConstraintF>,
+        addend: &Self,
+    ) -> Result<Self, SynthesisError> {
+        let addend = cond.select(addend, &Self::zero())?;
+        Ok(self.clone() + addend)
+    }
+
+    /// Enforces that `c == a + b`.
+    ///
+    /// This is the core check for additively homomorphic commitments. Both
+    /// the addition and the equality check correctly handle the identity.
+    #[tracing::instrument(target = "r1cs")]
+    fn enforce_homomorphic_sum(c: &Self, a: &Self, b: &Self) -> Result<(), SynthesisError> {
+        c.enforce_equal(&(a.clone() + b))
+    }
+
+    /// Computes `k * self` for a small constant `k`.
+    ///
+    /// This computes both the binary expansion and the non-adjacent form
+    /// (NAF) of `k` at synthesis time, and evaluates whichever of the two
+    /// resulting addition-subtraction chains is shorter, with one doubling
+    /// per digit and one addition or subtraction per non-zero digit. The NAF
+    /// has the fewest non-zero digits, but may be one digit longer than the
+    /// binary expansion (e.g., `3 = 4 - 1`), so neither is always cheaper.
+    /// Either way, this is much cheaper than the generic bit-by-bit scalar
+    /// multiplication when `k` is small.
+    #[tracing::instrument(target = "r1cs")]
+    fn mul_by_small_constant(&self, k: u64) -> Result<Self, SynthesisError> {
+        match k {
+            0 => return Ok(Self::zero()),
+            1 => return Ok(self.clone()),
+            _ => {},
+        }
+
+        // Compute the little-endian binary and NAF digits of `k`. We use `u128`
+        // for the latter to avoid overflowing when `k` is close to `u64::MAX`.
+        let binary = (0..u64::BITS - k.leading_zeros())
+            .map(|i| ((k >> i) & 1) as i8)
+            .collect::<Vec<_>>();
+        let mut naf = Vec::new();
+        let mut k = u128::from(k);
+        while k > 0 {
+            if k % 2 == 1 {
+                let digit = 2 - (k % 4) as i8;
+                naf.push(digit);
+                k = if digit == 1 { k - 1 } else { k + 1 };
+            } else {
+                naf.push(0);
+            }
+            k /= 2;
+        }
+
+        // Both chains start from `self`, and then perform one doubling per
+        // remaining digit and one addition or subtraction per remaining
+        // non-zero digit.
+        let cost = |digits: &[i8]| digits.len() + digits.iter().filter(|d| **d != 0).count();
+        let digits = if cost(&naf) < cost(&binary) {
+            naf
+        } else {
+            binary
+        };
+
+        // The most significant digit is always `1`.
+        let mut result = self.clone();
+        for &digit in digits.iter().rev().skip(1) {
+            result.double_in_place()?;
+            match digit {
+                1 => result += self,
+                -1 => result -= self,
+                _ => {},
+            }
+        }
+        Ok(result)
+    }
+
     /// Computes `bits * self`, where `bits` is a little-endian
     /// `Boolean` representation of a scalar.
     #[tracing::instrument(target = "r1cs", skip(bits))]
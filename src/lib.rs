@@ -39,6 +39,10 @@ pub mod boolean;
 /// Finite field arithmetic.
 pub mod fields;
 
+/// This module contains `Fixed`, an R1CS equivalent of a signed fixed-point
+/// number.
+pub mod fixed;
+
 /// Implementations of elliptic curve group arithmetic for popular curve models.
 pub mod groups;
 
@@ -64,6 +68,9 @@ pub mod poly;
 /// list of variables.
 pub mod select;
 
+/// This module contains a gadget for a single step of a simple stack machine.
+pub mod stack_machine;
+
 #[cfg(test)]
 pub(crate) mod test_utils;
 
@@ -10,7 +10,7 @@ use crate::{
     },
     R1CSVar,
 };
-use ark_ff::{batch_inversion, PrimeField};
+use ark_ff::{batch_inversion, FftField, PrimeField};
 use ark_relations::r1cs::SynthesisError;
 use ark_std::{
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
@@ -236,6 +236,49 @@ impl<F: PrimeField> EvaluationsVar<F> {
 
         Ok(res)
     }
+
+    /// Enforces that the polynomial of degree less than `domain_size` which
+    /// interpolates `evals` over the radix-2 subgroup of order `domain_size`
+    /// evaluates to `claimed_eval` at `point`.
+    ///
+    /// This uses the barycentric formula
+    /// `p(x) = (x^n - 1) / n * sum_i evals[i] * g^i / (x - g^i)`, where `g` is
+    /// the generator of the subgroup and `n = domain_size`. This additionally
+    /// enforces that `point` does not lie in the subgroup.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `domain_size` is not a power of two, if `F` does
+    /// not have a subgroup of order `domain_size`, or if
+    /// `evals.len() != domain_size`.
+    #[tracing::instrument(target = "r1cs")]
+    pub fn enforce_low_degree_at_point(
+        evals: &[FpVar<F>],
+        domain_size: usize,
+        point: &FpVar<F>,
+        claimed_eval: &FpVar<F>,
+    ) -> Result<(), SynthesisError> {
+        assert!(domain_size.is_power_of_two());
+        assert_eq!(
+            evals.len(),
+            domain_size,
+            "evaluations and domain has different sizes"
+        );
+        let gen = F::get_root_of_unity(domain_size as u64)
+            .expect("field does not have a subgroup of the given size");
+        let domain = Radix2DomainVar::new(gen, domain_size.trailing_zeros() as u64, FpVar::one())?;
+
+        // This enforces that `point` is not in the subgroup, which means that
+        // all denominators below are non-zero.
+        let vanishing_at_point = point.pow_by_constant(&[domain.size()])? - F::one();
+        vanishing_at_point.enforce_not_equal(&FpVar::zero())?;
+
+        let mut sum = FpVar::zero();
+        for (eval, element) in evals.iter().zip(domain.elements()) {
+            sum += (eval * &element).mul_by_inverse_unchecked(&(point - &element))?;
+        }
+        (claimed_eval * F::from(domain.size())).enforce_equal(&(vanishing_at_point * sum))
+    }
 }
 
 impl<'a, 'b, F: PrimeField> Add<&'a EvaluationsVar<F>> for &'b EvaluationsVar<F> {
@@ -500,4 +543,32 @@ mod tests {
 
         assert!(cs.is_satisfied().unwrap());
     }
+
+    #[test]
+    fn test_enforce_low_degree_at_point() {
+        let mut rng = test_rng();
+        let poly = DensePolynomial::rand(7, &mut rng);
+        let gen = Fr::get_root_of_unity(1 << 3).unwrap();
+        let evals = (0..(1 << 3))
+            .map(|i| poly.evaluate(&gen.pow([i])))
+            .collect::<Vec<_>>();
+        let point = Fr::rand(&mut rng);
+        let expected = poly.evaluate(&point);
+
+        for (claimed, should_pass) in [(expected, true), (expected + Fr::one(), false)] {
+            let cs = ConstraintSystem::new_ref();
+            let evals_var =
+                Vec::<FpVar<Fr>>::new_witness(ns!(cs, "evals"), || Ok(evals.as_slice())).unwrap();
+            let point_var = FpVar::new_input(ns!(cs, "point"), || Ok(point)).unwrap();
+            let claimed_var = FpVar::new_witness(ns!(cs, "claimed"), || Ok(claimed)).unwrap();
+            EvaluationsVar::enforce_low_degree_at_point(
+                &evals_var,
+                1 << 3,
+                &point_var,
+                &claimed_var,
+            )
+            .unwrap();
+            assert_eq!(cs.is_satisfied().unwrap(), should_pass);
+        }
+    }
 }
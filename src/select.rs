@@ -1,7 +1,7 @@
 use crate::prelude::*;
 use ark_ff::Field;
 use ark_relations::r1cs::SynthesisError;
-use ark_std::vec::Vec;
+use ark_std::{collections::BTreeMap, vec::Vec};
 /// Generates constraints for selecting between one of two values.
 pub trait CondSelectGadget<ConstraintF: Field>: Sized + Clone {
     /// If `cond == &Boolean::TRUE`, then this returns `true_value`; else,
@@ -63,6 +63,41 @@ pub trait CondSelectGadget<ConstraintF: Field>: Sized + Clone {
     }
 }
 
+/// Selects between the maps `true_value` and `false_value` key-by-key: if
+/// `cond == &Boolean::TRUE`, then this returns `true_value`; else, returns
+/// `false_value`.
+///
+/// If `cond` is a constant, this simply clones the selected map, and does not
+/// create any new variables or constraints.
+///
+/// # Errors
+///
+/// This function returns `SynthesisError::Unsatisfiable` if `true_value` and
+/// `false_value` do not have identical key sets.
+pub fn conditionally_select_map<ConstraintF, K, V>(
+    cond: &Boolean<ConstraintF>,
+    true_value: &BTreeMap<K, V>,
+    false_value: &BTreeMap<K, V>,
+) -> Result<BTreeMap<K, V>, SynthesisError>
+where
+    ConstraintF: Field,
+    K: Ord + Clone,
+    V: CondSelectGadget<ConstraintF>,
+{
+    if !true_value.keys().eq(false_value.keys()) {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+    match cond {
+        Boolean::Constant(true) => Ok(true_value.clone()),
+        Boolean::Constant(false) => Ok(false_value.clone()),
+        _ => true_value
+            .iter()
+            .zip(false_value.values())
+            .map(|((k, t), f)| Ok((k.clone(), V::conditionally_select(cond, t, f)?)))
+            .collect(),
+    }
+}
+
 /// Performs a lookup in a 4-element table using two bits.
 pub trait TwoBitLookupGadget<ConstraintF: Field>: Sized {
     /// The type of values being looked up.
@@ -107,3 +142,61 @@ pub trait ThreeBitCondNegLookupGadget<ConstraintF: Field>: Sized {
         constants: &[Self::TableConstant],
     ) -> Result<Self, SynthesisError>;
 }
+
+#[cfg(test)]
+mod test {
+    use super::conditionally_select_map;
+    use crate::{alloc::AllocVar, boolean::Boolean, fields::fp::FpVar, R1CSVar};
+    use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+    use ark_std::collections::BTreeMap;
+    use ark_test_curves::bls12_381::Fr;
+
+    #[test]
+    fn select_map() -> Result<(), SynthesisError> {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let a = [("pc", 1u64), ("acc", 2), ("sp", 3)]
+            .into_iter()
+            .map(|(k, v)| Ok((k, FpVar::new_witness(cs.clone(), || Ok(Fr::from(v)))?)))
+            .collect::<Result<BTreeMap<_, _>, SynthesisError>>()?;
+        let b = [("pc", 4u64), ("acc", 5), ("sp", 6)]
+            .into_iter()
+            .map(|(k, v)| Ok((k, FpVar::new_witness(cs.clone(), || Ok(Fr::from(v)))?)))
+            .collect::<Result<BTreeMap<_, _>, SynthesisError>>()?;
+
+        for cond_value in [false, true] {
+            let expected = if cond_value { &a } else { &b };
+            for cond in [
+                Boolean::new_witness(cs.clone(), || Ok(cond_value))?,
+                Boolean::constant(cond_value),
+            ] {
+                let selected = conditionally_select_map(&cond, &a, &b)?;
+                assert_eq!(selected.len(), expected.len());
+                for (k, v) in &selected {
+                    assert_eq!(v.value()?, expected[k].value()?);
+                }
+            }
+        }
+        assert!(cs.is_satisfied().unwrap());
+        Ok(())
+    }
+
+    #[test]
+    fn select_map_mismatched_keys() -> Result<(), SynthesisError> {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let cond = Boolean::new_witness(cs.clone(), || Ok(true))?;
+        let one = FpVar::new_witness(cs.clone(), || Ok(Fr::from(1u64)))?;
+        let a = BTreeMap::from([("pc", one.clone()), ("acc", one.clone())]);
+        let b = BTreeMap::from([("pc", one.clone()), ("sp", one.clone())]);
+        assert!(matches!(
+            conditionally_select_map(&cond, &a, &b),
+            Err(SynthesisError::Unsatisfiable)
+        ));
+
+        let c = BTreeMap::from([("pc", one.clone())]);
+        assert!(matches!(
+            conditionally_select_map(&cond, &a, &c),
+            Err(SynthesisError::Unsatisfiable)
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use ark_ff::PrimeField;
+use ark_relations::r1cs::SynthesisError;
+
+use crate::{fields::fp::FpVar, prelude::*, Vec};
+
+/// Pushes the operand onto the stack.
+pub const PUSH: u8 = 0;
+/// Pops the top element off the stack.
+pub const POP: u8 = 1;
+/// Pops the top two elements off the stack, and pushes their sum.
+pub const ADD: u8 = 2;
+/// Pops the top two elements off the stack, and pushes their product.
+pub const MUL: u8 = 3;
+
+/// Executes a single step of a simple stack machine, and outputs the new stack
+/// and stack pointer.
+///
+/// The stack has a fixed capacity of `stack.len()` elements, and `sp` is the
+/// number of elements currently on the stack. That is, the top of the stack is
+/// `stack[sp - 1]`. Slots that are freed by `POP`, `ADD`, and `MUL` are reset
+/// to zero. `operand` is only used by `PUSH`, and is ignored otherwise.
+///
+/// The constraint system is unsatisfiable if `opcode` is not one of
+/// [`PUSH`], [`POP`], [`ADD`], and [`MUL`], if `sp > stack.len()`, or if the
+/// step would overflow or underflow the stack.
+#[tracing::instrument(target = "r1cs")]
+pub fn step_stack_machine<F: PrimeField>(
+    stack: &[FpVar<F>],
+    sp: &FpVar<F>,
+    opcode: &UInt8<F>,
+    operand: &FpVar<F>,
+) -> Result<(Vec<FpVar<F>>, FpVar<F>), SynthesisError> {
+    let capacity = stack.len();
+
+    // One-hot selectors for the opcode. Since at most one of them can be set,
+    // enforcing that their sum is one enforces that the opcode is valid.
+    let is_op = |op| opcode.is_eq(&UInt8::constant(op));
+    let (is_push, is_pop, is_add, is_mul) = (is_op(PUSH)?, is_op(POP)?, is_op(ADD)?, is_op(MUL)?);
+    enforce_exactly_one(&[&is_push, &is_pop, &is_add, &is_mul])?;
+    let is_binop = &is_add | &is_mul;
+
+    // One-hot selectors for the stack pointer, i.e., `sp_is[j]` is set if and
+    // only if `sp == j`. This also enforces that `sp <= capacity`.
+    let sp_is = (0..=capacity)
+        .map(|j| sp.is_eq(&FpVar::constant(F::from(j as u64))))
+        .collect::<Result<Vec<_>, _>>()?;
+    enforce_exactly_one(&sp_is.iter().collect::<Vec<_>>())?;
+
+    // Reject overflows and underflows.
+    (&is_push & &sp_is[capacity]).enforce_equal(&Boolean::FALSE)?;
+    (&is_pop & &sp_is[0]).enforce_equal(&Boolean::FALSE)?;
+    let has_fewer_than_two = match sp_is.get(1) {
+        Some(sp_is_one) => &sp_is[0] | sp_is_one,
+        None => sp_is[0].clone(),
+    };
+    (&is_binop & has_fewer_than_two).enforce_equal(&Boolean::FALSE)?;
+
+    // The top two elements of the stack, i.e., `stack[sp - 1]` and
+    // `stack[sp - 2]`.
+    let mut top = FpVar::zero();
+    let mut second = FpVar::zero();
+    for (k, element) in stack.iter().enumerate() {
+        top += FpVar::from(sp_is[k + 1].clone()) * element;
+        if let Some(sp_is_k_plus_two) = sp_is.get(k + 2) {
+            second += FpVar::from(sp_is_k_plus_two.clone()) * element;
+        }
+    }
+    let binop_result = is_mul.select(&(&second * &top), &(&second + &top))?;
+
+    let mut new_stack = Vec::with_capacity(capacity);
+    for (k, element) in stack.iter().enumerate() {
+        // The slot at `sp` receives the operand of `PUSH`, the slot at
+        // `sp - 1` is freed by `POP`, `ADD`, and `MUL`, and the slot at
+        // `sp - 2` receives the result of `ADD` and `MUL`.
+        let is_pushed_to = &is_push & &sp_is[k];
+        let is_freed = !&is_push & &sp_is[k + 1];
+        let is_binop_target = match sp_is.get(k + 2) {
+            Some(sp_is_k_plus_two) => &is_binop & sp_is_k_plus_two,
+            None => Boolean::FALSE,
+        };
+        let mut new_element = is_binop_target.select(&binop_result, element)?;
+        new_element = is_freed.select(&FpVar::zero(), &new_element)?;
+        new_element = is_pushed_to.select(operand, &new_element)?;
+        new_stack.push(new_element);
+    }
+
+    let new_sp = sp + FpVar::from(is_push) - FpVar::from(is_pop) - FpVar::from(is_binop);
+    Ok((new_stack, new_sp))
+}
+
+/// Enforces that exactly one of the mutually exclusive `bits` is set.
+fn enforce_exactly_one<F: PrimeField>(bits: &[&Boolean<F>]) -> Result<(), SynthesisError> {
+    bits.iter()
+        .fold(FpVar::zero(), |acc, b| acc + FpVar::from((*b).clone()))
+        .enforce_equal(&FpVar::one())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{step_stack_machine, ADD, MUL, POP, PUSH};
+    use crate::{alloc::AllocVar, fields::fp::FpVar, uint8::UInt8, R1CSVar};
+    use ark_relations::r1cs::{ConstraintSystem, SynthesisError};
+    use ark_test_curves::bls12_381::Fr;
+
+    const CAPACITY: usize = 4;
+
+    /// Runs `program` on an empty stack, and outputs the final stack, the final
+    /// stack pointer, and whether the constraint system is satisfied.
+    fn run(program: &[(u8, u64)]) -> Result<(Vec<Fr>, Fr, bool), SynthesisError> {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let mut stack =
+            Vec::<FpVar<Fr>>::new_witness(cs.clone(), || Ok([Fr::from(0u64); CAPACITY]))?;
+        let mut sp = FpVar::new_witness(cs.clone(), || Ok(Fr::from(0u64)))?;
+        for (opcode, operand) in program {
+            let opcode = UInt8::new_witness(cs.clone(), || Ok(*opcode))?;
+            let operand = FpVar::new_witness(cs.clone(), || Ok(Fr::from(*operand)))?;
+            let (new_stack, new_sp) = step_stack_machine(&stack, &sp, &opcode, &operand)?;
+            stack = new_stack;
+            sp = new_sp;
+        }
+        Ok((stack.value()?, sp.value()?, cs.is_satisfied().unwrap()))
+    }
+
+    #[test]
+    fn push_push_add() -> Result<(), SynthesisError> {
+        let (stack, sp, is_satisfied) = run(&[(PUSH, 2), (PUSH, 3), (ADD, 0)])?;
+        assert!(is_satisfied);
+        assert_eq!(sp, Fr::from(1u64));
+        assert_eq!(stack, [5u64, 0, 0, 0].map(Fr::from));
+        Ok(())
+    }
+
+    #[test]
+    fn longer_program() -> Result<(), SynthesisError> {
+        let program = [
+            (PUSH, 7),
+            (PUSH, 2),
+            (PUSH, 3),
+            (PUSH, 4),
+            (MUL, 0),
+            (POP, 0),
+            (PUSH, 6),
+            (MUL, 0),
+            (ADD, 0),
+        ];
+        let (stack, sp, is_satisfied) = run(&program)?;
+        assert!(is_satisfied);
+        assert_eq!(sp, Fr::from(1u64));
+        // 7 + 2 * 6
+        assert_eq!(stack, [19u64, 0, 0, 0].map(Fr::from));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_steps() -> Result<(), SynthesisError> {
+        // Underflows.
+        assert!(!run(&[(POP, 0)])?.2);
+        assert!(!run(&[(ADD, 0)])?.2);
+        assert!(!run(&[(PUSH, 1), (MUL, 0)])?.2);
+        // Overflow.
+        assert!(!run(&[(PUSH, 1); CAPACITY + 1])?.2);
+        // Invalid opcode.
+        assert!(!run(&[(PUSH, 1), (4, 0)])?.2);
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use ark_ff::PrimeField;
+use ark_relations::r1cs::SynthesisError;
+
+use crate::fields::fp::FpVar;
+
+use super::*;
+
+impl<const N: usize, T: PrimUInt, F: PrimeField> UInt<N, T, F> {
+    /// Outputs the bitwise majority of `values`. That is, the `i`-th bit of
+    /// the output is set if and only if the `i`-th bit is set in more than
+    /// half of `values`.
+    ///
+    /// For every bit position, this counts the number of set bits, and then
+    /// compares the count against `values.len() / 2` via a bit decomposition.
+    /// In total, this requires roughly `N * (log2(values.len()) + 2)`
+    /// constraints.
+    ///
+    /// ```
+    /// # fn main() -> Result<(), ark_relations::r1cs::SynthesisError> {
+    /// // We'll use the BLS12-381 scalar field for our constraints.
+    /// use ark_test_curves::bls12_381::Fr;
+    /// use ark_relations::r1cs::*;
+    /// use ark_r1cs_std::prelude::*;
+    ///
+    /// let cs = ConstraintSystem::<Fr>::new_ref();
+    /// let a = UInt8::new_witness(cs.clone(), || Ok(0b1100))?;
+    /// let b = UInt8::new_witness(cs.clone(), || Ok(0b1010))?;
+    /// let c = UInt8::new_witness(cs.clone(), || Ok(0b0110))?;
+    ///
+    /// UInt8::bitwise_majority(&[a, b, c])?.enforce_equal(&UInt8::constant(0b1110))?;
+    /// assert!(cs.is_satisfied().unwrap());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[tracing::instrument(target = "r1cs", skip(values))]
+    pub fn bitwise_majority(values: &[Self]) -> Result<Self, SynthesisError> {
+        let n = values.len();
+        // The smallest count that is more than half of `n`.
+        let threshold = n / 2 + 1;
+        // The number of bits required to represent any count in `[0, n]`.
+        let count_size = (usize::BITS - n.leading_zeros()) as usize;
+        // `count + 2^count_size - threshold` is a non-negative
+        // `(count_size + 1)`-bit integer, whose most significant bit is set if
+        // and only if `count >= threshold`.
+        let offset = F::from(2u64).pow([count_size as u64]) - F::from(threshold as u64);
+
+        let mut bits = Vec::with_capacity(N);
+        for i in 0..N {
+            let count = values
+                .iter()
+                .fold(FpVar::zero(), |acc, v| acc + FpVar::from(v.bits[i].clone()));
+            let (count_bits, _) = (count + offset).to_bits_le_with_top_bits_zero(count_size + 1)?;
+            bits.push(count_bits[count_size].clone());
+        }
+        Ok(Self::from_bits_le(&bits))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        alloc::{AllocVar, AllocationMode},
+        prelude::EqGadget,
+        test_utils::modes,
+        R1CSVar,
+    };
+    use ark_relations::r1cs::ConstraintSystem;
+    use ark_std::rand::Rng;
+    use ark_test_curves::bls12_381::Fr;
+
+    fn reference_majority(values: &[u8]) -> u8 {
+        (0..8)
+            .filter(|i| 2 * values.iter().filter(|v| (*v >> i) & 1 == 1).count() > values.len())
+            .fold(0, |acc, i| acc | (1 << i))
+    }
+
+    fn uint_majority(values: &[u8], modes: &[AllocationMode]) -> Result<(), SynthesisError> {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let vars = values
+            .iter()
+            .zip(modes)
+            .map(|(v, mode)| UInt8::new_variable(cs.clone(), || Ok(*v), *mode))
+            .collect::<Result<Vec<_>, _>>()?;
+        let computed = UInt8::bitwise_majority(&vars)?;
+        let all_constant = modes.iter().all(|mode| *mode == AllocationMode::Constant);
+        let expected_mode = if all_constant {
+            AllocationMode::Constant
+        } else {
+            AllocationMode::Witness
+        };
+        let expected =
+            UInt8::new_variable(cs.clone(), || Ok(reference_majority(values)), expected_mode)?;
+        assert_eq!(expected.value(), computed.value());
+        expected.enforce_equal(&computed)?;
+        if !all_constant {
+            assert!(cs.is_satisfied().unwrap());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn u8_majority_of_three() -> Result<(), SynthesisError> {
+        let mut rng = ark_std::test_rng();
+        for _ in 0..100 {
+            let values: [u8; 3] = rng.gen();
+            for mode_a in modes() {
+                for mode_b in modes() {
+                    for mode_c in modes() {
+                        uint_majority(&values, &[mode_a, mode_b, mode_c])?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn u8_majority_of_many() -> Result<(), SynthesisError> {
+        let mut rng = ark_std::test_rng();
+        for n in [1, 2, 4, 5, 8] {
+            for _ in 0..100 {
+                let values = (0..n).map(|_| rng.gen()).collect::<Vec<u8>>();
+                uint_majority(&values, &vec![AllocationMode::Witness; n])?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -3,6 +3,7 @@ use ark_ff::{Field, PrimeField, ToConstraintField};
 use ark_relations::r1cs::{Namespace, SynthesisError};
 
 use crate::{
+    cmp::CmpGadget,
     convert::{ToBitsGadget, ToConstraintFieldGadget},
     fields::fp::{AllocatedFp, FpVar},
     prelude::*,
@@ -71,6 +72,102 @@ impl<F: Field> UInt8<F> {
     }
 }
 
+impl<F: PrimeField> UInt8<F> {
+    /// Enforces that `data` is the expansion of the run-length encoding
+    /// `(symbols, counts)`. That is, `data` consists of `counts[0]` copies of
+    /// `symbols[0]`, followed by `counts[1]` copies of `symbols[1]`, and so on.
+    ///
+    /// Additionally, this enforces that every count is non-zero, and that the
+    /// counts sum to `data.len()`.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `symbols.len() != counts.len()`.
+    #[tracing::instrument(target = "r1cs", skip(data, symbols, counts))]
+    pub fn enforce_rle(
+        data: &[Self],
+        symbols: &[Self],
+        counts: &[Self],
+    ) -> Result<(), SynthesisError> {
+        assert_eq!(symbols.len(), counts.len());
+
+        // `starts[i]` is the position in `data` at which the `i`-th run starts.
+        let mut starts = Vec::with_capacity(counts.len());
+        let mut total = FpVar::zero();
+        for count in counts {
+            count.enforce_not_equal(&Self::constant(0))?;
+            starts.push(total.clone());
+            total += count.to_fp()?;
+        }
+        total.enforce_equal(&FpVar::constant(F::from(data.len() as u64)))?;
+
+        // Since the counts are non-negative and sum to `data.len()`, every
+        // start lies in `[0, data.len()]`. Hence, for every position `j`, the
+        // value `j - start + 2^diff_bits` is a non-negative
+        // `(diff_bits + 1)`-bit integer, whose most significant bit is set if
+        // and only if `j >= start`.
+        let diff_bits = (usize::BITS - data.len().leading_zeros()) as usize;
+        let offset = F::from(2u64).pow([diff_bits as u64]);
+        let is_at_or_after = |j: usize, start: &FpVar<F>| -> Result<Boolean<F>, SynthesisError> {
+            let shifted = FpVar::constant(F::from(j as u64) + offset) - start;
+            let (bits, _) = shifted.to_bits_le_with_top_bits_zero(diff_bits + 1)?;
+            Ok(bits[diff_bits].clone())
+        };
+
+        // Position `j` lies in the `i`-th run if and only if `j >= starts[i]`
+        // and `j < starts[i + 1]`.
+        for (j, byte) in data.iter().enumerate() {
+            let after_start = starts
+                .iter()
+                .map(|start| is_at_or_after(j, start))
+                .collect::<Result<Vec<_>, _>>()?;
+            for (i, symbol) in symbols.iter().enumerate() {
+                let in_run = match after_start.get(i + 1) {
+                    Some(after_next_start) => &after_start[i] & !after_next_start,
+                    None => after_start[i].clone(),
+                };
+                byte.conditional_enforce_equal(symbol, &in_run)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Enforces that `nibbles` is a valid binary-coded decimal (BCD)
+    /// encoding, i.e., that every nibble lies in `0..=9`.
+    #[tracing::instrument(target = "r1cs", skip(nibbles))]
+    pub fn enforce_valid_bcd(nibbles: &[Self]) -> Result<(), SynthesisError> {
+        let nine = Self::constant(9);
+        for nibble in nibbles {
+            nibble.is_le(&nine)?.enforce_equal(&Boolean::TRUE)?;
+        }
+        Ok(())
+    }
+
+    /// Converts the binary-coded decimal (BCD) encoding `nibbles` into the
+    /// integer it represents, i.e., `sum_i nibbles[i] * 10^i`. That is,
+    /// `nibbles[0]` is the least significant digit.
+    ///
+    /// This also enforces that `nibbles` is a valid BCD encoding, so there is
+    /// no need to call [`UInt8::enforce_valid_bcd`] separately.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `4 * nibbles.len() >= F::MODULUS_BIT_SIZE`, since
+    /// the result might otherwise overflow.
+    #[tracing::instrument(target = "r1cs", skip(nibbles))]
+    pub fn bcd_to_uint(nibbles: &[Self]) -> Result<FpVar<F>, SynthesisError> {
+        assert!(4 * nibbles.len() < F::MODULUS_BIT_SIZE as usize);
+        Self::enforce_valid_bcd(nibbles)?;
+        let mut result = FpVar::zero();
+        let mut ten_to_the_i = F::one();
+        for nibble in nibbles {
+            result += nibble.to_fp()? * ten_to_the_i;
+            ten_to_the_i *= F::from(10u8);
+        }
+        Ok(result)
+    }
+}
+
 /// Parses the `Vec<UInt8<ConstraintF>>` in fixed-sized
 /// `ConstraintF::MODULUS_BIT_SIZE - 1` chunks and converts each chunk, which is
 /// assumed to be little-endian, to its `FpVar<ConstraintF>` representation.
@@ -280,4 +377,70 @@ mod test {
             )
         }
     }
+
+    #[test]
+    fn test_uint8_enforce_rle() -> Result<(), SynthesisError> {
+        let data = b"aaabccccd";
+        let symbols = b"abcd";
+        let counts = [3u8, 1, 4, 1];
+
+        let check = |data: &[u8], symbols: &[u8], counts: &[u8]| {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let data = UInt8::new_witness_vec(cs.clone(), data)?;
+            let symbols = UInt8::new_witness_vec(cs.clone(), symbols)?;
+            let counts = UInt8::new_witness_vec(cs.clone(), counts)?;
+            UInt8::enforce_rle(&data, &symbols, &counts)?;
+            Ok::<_, SynthesisError>(cs.is_satisfied().unwrap())
+        };
+
+        assert!(check(data, symbols, &counts)?);
+        assert!(check(b"z", b"z", &[1])?);
+        assert!(check(b"", b"", &[])?);
+
+        // Corrupted symbol.
+        assert!(!check(data, b"abed", &counts)?);
+        // Corrupted count which still sums to `data.len()`.
+        assert!(!check(data, symbols, &[2, 2, 4, 1])?);
+        // Counts which do not sum to `data.len()`.
+        assert!(!check(data, symbols, &[3, 1, 4, 2])?);
+        // Zero counts are rejected, even if the expansion is correct.
+        assert!(!check(data, b"abxcd", &[3, 1, 0, 4, 1])?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_uint8_bcd() -> Result<(), SynthesisError> {
+        for (nibbles, expected) in [
+            (vec![], 0u64),
+            (vec![7], 7),
+            (vec![0, 1], 10),
+            (vec![9, 9, 9, 9], 9999),
+            (vec![4, 3, 2, 1, 0, 0], 1234),
+            (vec![5, 0, 2, 0, 8, 1], 180205),
+        ] {
+            for mode in [Constant, Witness] {
+                let cs = ConstraintSystem::<Fr>::new_ref();
+                let nibbles = nibbles
+                    .iter()
+                    .map(|n| UInt8::new_variable(cs.clone(), || Ok(*n), mode))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let result = UInt8::bcd_to_uint(&nibbles)?;
+                assert_eq!(result.value()?, Fr::from(expected));
+                assert!(cs.is_satisfied().unwrap());
+            }
+        }
+
+        for invalid in [10u8, 15, 16, 0x99, 255] {
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let nibbles = UInt8::new_witness_vec(cs.clone(), &[1, invalid, 3])?;
+            UInt8::enforce_valid_bcd(&nibbles)?;
+            assert!(!cs.is_satisfied().unwrap());
+
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let nibbles = UInt8::new_witness_vec(cs.clone(), &[1, invalid, 3])?;
+            let _ = UInt8::bcd_to_uint(&nibbles)?;
+            assert!(!cs.is_satisfied().unwrap());
+        }
+        Ok(())
+    }
 }